@@ -1,4 +1,5 @@
 use faceit::HttpClient;
+use faceit::http::MatchType;
 
 #[tokio::main]
 async fn main() -> Result<(), faceit::error::Error> {
@@ -91,7 +92,7 @@ async fn main() -> Result<(), faceit::error::Error> {
         Err(e) => eprintln!("Error: {}", e),
     }
 
-    match hub.matches(Some("all"), Some(0), Some(20)).await {
+    match hub.matches(Some(MatchType::All), Some(0), Some(20)).await {
         Ok(matches) => {
             println!("Found {} matches", matches.items.len());
         }
@@ -116,7 +117,10 @@ async fn main() -> Result<(), faceit::error::Error> {
         Err(e) => eprintln!("Error: {}", e),
     }
 
-    match championship.matches(Some("all"), Some(0), Some(20)).await {
+    match championship
+        .matches(Some(MatchType::All), Some(0), Some(20))
+        .await
+    {
         Ok(matches) => {
             println!("Found {} matches", matches.items.len());
         }
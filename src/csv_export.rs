@@ -0,0 +1,219 @@
+//! CSV export for flat list responses
+//!
+//! Each list is written with one header row followed by one row per item.
+//! Nested fields are flattened into plain columns; multi-valued fields are
+//! joined with `;`. An empty list produces no output.
+
+use crate::error::Error;
+use crate::types::*;
+use serde::Serialize;
+use std::io::Write;
+
+/// Write `rows` as CSV with a header row
+fn write_rows<W, R>(writer: W, rows: impl IntoIterator<Item = R>) -> Result<(), Error>
+where
+    W: Write,
+    R: Serialize,
+{
+    let mut writer = csv::Writer::from_writer(writer);
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush().map_err(|e| Error::Csv(e.into()))?;
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct RankingRow<'a> {
+    player_id: &'a str,
+    nickname: &'a str,
+    position: i64,
+    faceit_elo: i64,
+    game_skill_level: i64,
+    country: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct BanRow<'a> {
+    user_id: &'a str,
+    nickname: &'a str,
+    game: &'a str,
+    ban_type: &'a str,
+    reason: &'a str,
+    starts_at: String,
+    ends_at: String,
+}
+
+#[derive(Serialize)]
+struct HubMemberRow<'a> {
+    user_id: &'a str,
+    nickname: &'a str,
+    roles: String,
+    faceit_url: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct MatchHistoryRow<'a> {
+    match_id: &'a str,
+    game_id: &'a str,
+    region: Option<&'a str>,
+    match_type: Option<&'a str>,
+    game_mode: Option<&'a str>,
+    competition_name: Option<&'a str>,
+    status: &'a str,
+    started_at: Option<i64>,
+    finished_at: Option<i64>,
+    winner: Option<&'a str>,
+    score: String,
+}
+
+impl GlobalRankingList {
+    /// Write the rankings as CSV
+    ///
+    /// Columns: `player_id`, `nickname`, `position`, `faceit_elo`,
+    /// `game_skill_level`, `country`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Csv`] if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let ranking = client.get_global_ranking("cs2", "EU", None, Some(0), Some(100)).await?;
+    /// ranking.to_csv(std::io::stdout())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_rows(
+            writer,
+            self.items.iter().map(|ranking| RankingRow {
+                player_id: &ranking.player_id,
+                nickname: &ranking.nickname,
+                position: ranking.position,
+                faceit_elo: ranking.faceit_elo,
+                game_skill_level: ranking.game_skill_level,
+                country: ranking.country.as_deref(),
+            }),
+        )
+    }
+}
+
+impl PlayerBansList {
+    /// Write the bans as CSV
+    ///
+    /// Columns: `user_id`, `nickname`, `game`, `ban_type`, `reason`,
+    /// `starts_at`, `ends_at` (RFC 3339).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Csv`] if writing fails.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_rows(
+            writer,
+            self.items.iter().map(|ban| BanRow {
+                user_id: &ban.user_id,
+                nickname: &ban.nickname,
+                game: &ban.game,
+                ban_type: &ban.ban_type,
+                reason: &ban.reason,
+                starts_at: ban.starts_at.to_rfc3339(),
+                ends_at: ban.ends_at.to_rfc3339(),
+            }),
+        )
+    }
+}
+
+impl HubMembers {
+    /// Write the members as CSV
+    ///
+    /// Columns: `user_id`, `nickname`, `roles` (`;`-separated), `faceit_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Csv`] if writing fails.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_rows(
+            writer,
+            self.items.iter().map(|member| HubMemberRow {
+                user_id: &member.user_id,
+                nickname: &member.nickname,
+                roles: member.roles.as_deref().unwrap_or_default().join(";"),
+                faceit_url: member.faceit_url.as_deref(),
+            }),
+        )
+    }
+}
+
+impl MatchHistoryList {
+    /// Write the match history as CSV
+    ///
+    /// Columns: `match_id`, `game_id`, `region`, `match_type`, `game_mode`,
+    /// `competition_name`, `status`, `started_at`, `finished_at`, `winner`,
+    /// `score` (e.g. `faction1=16;faction2=14`). Team rosters are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Csv`] if writing fails.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_rows(
+            writer,
+            self.items.iter().map(|history| {
+                let results = history.results.as_ref();
+                let mut score: Vec<(&String, &i64)> = results
+                    .and_then(|r| r.score.as_ref())
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                score.sort();
+
+                MatchHistoryRow {
+                    match_id: &history.match_id,
+                    game_id: &history.game_id,
+                    region: history.region.as_deref(),
+                    match_type: history.match_type.as_deref(),
+                    game_mode: history.game_mode.as_deref(),
+                    competition_name: history.competition_name.as_deref(),
+                    status: &history.status,
+                    started_at: history.started_at,
+                    finished_at: history.finished_at,
+                    winner: results.and_then(|r| r.winner.as_deref()),
+                    score: score
+                        .iter()
+                        .map(|(faction, points)| format!("{}={}", faction, points))
+                        .collect::<Vec<_>>()
+                        .join(";"),
+                }
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global_ranking_to_csv() {
+        let ranking: GlobalRankingList = parse_response(
+            r#"{"start": 0, "end": 2, "items": [
+                {"player_id": "p-1", "nickname": "alpha", "position": 1, "faceit_elo": 4000, "game_skill_level": 10, "country": "de"},
+                {"player_id": "p-2", "nickname": "beta", "position": 2, "faceit_elo": 3900, "game_skill_level": 10}
+            ]}"#,
+        )
+        .unwrap();
+
+        let mut out = Vec::new();
+        ranking.to_csv(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "player_id,nickname,position,faceit_elo,game_skill_level,country\n\
+             p-1,alpha,1,4000,10,de\n\
+             p-2,beta,2,3900,10,\n"
+        );
+    }
+}
@@ -1,9 +1,16 @@
 use thiserror::Error;
 
+/// Errors returned by the client and the response types
+///
+/// Marked `#[non_exhaustive]`: some variants only exist with certain cargo
+/// features enabled, so `match`es need a wildcard arm to keep compiling when a
+/// feature is enabled elsewhere in the dependency graph.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
+    #[cfg(feature = "http")]
     #[error("HTTP request error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(reqwest::Error),
 
     #[error("JSON parsing error: {0}")]
     Json(#[from] serde_json::Error),
@@ -11,18 +18,213 @@ pub enum Error {
     #[error("API error (status {0}): {1}")]
     Api(u16, String),
 
+    #[error("Operation timed out")]
+    Timeout,
+
+    /// The request could not be sent, e.g. the connection was refused
+    ///
+    /// Holds the underlying `reqwest::Error`, available through
+    /// [`source`](std::error::Error::source). It is reference-counted so that
+    /// coalesced requests can report it to every waiting caller.
+    #[error("Connection error: {0}")]
+    Connection(#[source] std::sync::Arc<dyn std::error::Error + Send + Sync>),
+
+    /// A polling helper gave up before the awaited state was reached
+    ///
+    /// Unlike [`Error::Timeout`], no request timed out; the caller's overall
+    /// deadline passed.
+    #[error("Gave up waiting after {0:?}")]
+    WaitTimeout(std::time::Duration),
+
+    #[error("Circuit breaker open; request not sent")]
+    CircuitOpen,
+
+    #[error("Unexpected content type {content_type} (status {status}): {snippet}")]
+    UnexpectedContentType {
+        status: u16,
+        content_type: String,
+        snippet: String,
+    },
+
     #[error("Invalid or missing API key")]
     InvalidApiKey,
 
+    #[error("Unavailable in this region (status {0}): {1}")]
+    GeoBlocked(u16, String),
+
     #[error("Server error (500)")]
     ServerError,
 
+    #[error("Match statistics not available yet (match status: {0})")]
+    StatsNotReady(String),
+
     #[error("Missing required parameter: {0}")]
     MissingParameter(String),
 
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+
     #[error("Invalid game ID: {0}")]
     InvalidGameId(String),
 
+    #[error("Invalid country code: {0}")]
+    InvalidCountry(String),
+
     #[error("Invalid data source: {0}")]
     InvalidDataSource(String),
+
+    #[cfg(feature = "csv")]
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[cfg(feature = "ndjson")]
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An error of a request sent to the API, with the request it came from
+    ///
+    /// Every error of a request the client sent is wrapped in this variant,
+    /// so concurrent calls can be told apart. Use [`Error::inner`] to match on
+    /// the underlying error.
+    #[error("{source} ({method} {url})")]
+    Request {
+        /// HTTP method of the request, e.g. `GET`
+        method: String,
+        /// URL the request was sent to, including the query string
+        url: String,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+#[cfg(feature = "http")]
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Error::Timeout
+        } else if err.is_connect() || err.is_request() {
+            Error::Connection(std::sync::Arc::new(err))
+        } else {
+            Error::Http(err)
+        }
+    }
+}
+
+impl Error {
+    /// Get the error without its request context
+    ///
+    /// Errors of requests sent by the client are wrapped in
+    /// [`Error::Request`]; this returns the wrapped error, or `self` for
+    /// errors that happened before a request was sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use faceit::error::Error;
+    ///
+    /// let error = Error::Request {
+    ///     method: "GET".to_string(),
+    ///     url: "https://open.faceit.com/data/v4/players/player-id".to_string(),
+    ///     source: Box::new(Error::Api(404, "Not found".to_string())),
+    /// };
+    /// assert!(matches!(error.inner(), Error::Api(404, _)));
+    /// ```
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Request { source, .. } => source.inner(),
+            other => other,
+        }
+    }
+
+    /// Unwrap the error from its request context
+    ///
+    /// See [`inner`](Self::inner).
+    pub fn into_inner(self) -> Error {
+        match self {
+            Error::Request { source, .. } => source.into_inner(),
+            other => other,
+        }
+    }
+
+    /// Get the URL of the request that failed, if a request was sent
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Error::Request { url, .. } => Some(url),
+            _ => None,
+        }
+    }
+
+    /// Get the HTTP status of the response that caused the error, if any
+    pub fn status(&self) -> Option<u16> {
+        match self.inner() {
+            #[cfg(feature = "http")]
+            Error::Http(e) => e.status().map(|status| status.as_u16()),
+            Error::Api(status, _)
+            | Error::GeoBlocked(status, _)
+            | Error::UnexpectedContentType { status, .. } => Some(*status),
+            Error::InvalidApiKey => Some(401),
+            Error::ServerError => Some(500),
+            _ => None,
+        }
+    }
+
+    /// Whether the API answered `404 Not Found`
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.inner(), Error::Api(404, _))
+    }
+
+    /// Take a cloneable snapshot of this error
+    ///
+    /// See [`ErrorSnapshot`].
+    pub fn snapshot(&self) -> ErrorSnapshot {
+        ErrorSnapshot::from(self)
+    }
+}
+
+/// Cloneable summary of an [`Error`]
+///
+/// [`Error`] wraps errors from `reqwest` and `serde_json` that cannot be
+/// cloned. A snapshot keeps the formatted message and the HTTP status, so it
+/// can be stored in types that derive `Clone`, e.g. per-item results of a
+/// batch of requests.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::error::{Error, ErrorSnapshot};
+///
+/// let error = Error::Api(404, "Not found".to_string());
+/// let snapshot: ErrorSnapshot = error.snapshot();
+/// assert_eq!(snapshot.status, Some(404));
+/// assert_eq!(snapshot.message, error.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorSnapshot {
+    /// HTTP status of the response that caused the error, if any
+    pub status: Option<u16>,
+    /// The error's message, as displayed by [`Error`]
+    pub message: String,
+}
+
+impl std::fmt::Display for ErrorSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ErrorSnapshot {}
+
+impl From<&Error> for ErrorSnapshot {
+    fn from(err: &Error) -> Self {
+        Self {
+            status: err.status(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<Error> for ErrorSnapshot {
+    fn from(err: Error) -> Self {
+        Self::from(&err)
+    }
 }
@@ -0,0 +1,178 @@
+//! Circuit breaker for API outages
+//!
+//! Once enough requests fail in a row, the client stops sending requests and
+//! fails fast with [`Error::CircuitOpen`] until a cooldown has elapsed. A
+//! single probe request is then let through: if it succeeds the circuit
+//! closes again, otherwise it re-opens for another cooldown.
+//!
+//! Install one with [`ClientBuilder::circuit_breaker`](super::ClientBuilder::circuit_breaker).
+
+use crate::error::Error;
+use std::sync::Mutex;
+use std::time::Duration;
+use web_time::Instant;
+
+/// Circuit breaker configuration
+///
+/// A request counts as failed if no response was received (timeouts,
+/// connection errors), or if the API answered with a 5xx status or
+/// `429 Too Many Requests`. Other client errors such as `404` do not count.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::CircuitBreaker;
+/// use std::time::Duration;
+///
+/// // Open after 5 failures within 30 seconds, stay open for a minute
+/// let breaker = CircuitBreaker::new(5)
+///     .window(Duration::from_secs(30))
+///     .cooldown(Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    pub(crate) failure_threshold: u32,
+    pub(crate) window: Duration,
+    pub(crate) cooldown: Duration,
+}
+
+impl CircuitBreaker {
+    /// Open the circuit after `failure_threshold` consecutive failures
+    ///
+    /// Defaults to a 60 second failure window and a 30 second cooldown.
+    /// A threshold of `0` is treated as `1`.
+    pub fn new(failure_threshold: u32) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    /// Only count failures that happen within this window of the first one
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// How long to reject requests once the circuit has opened
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+/// Current state of a client's circuit breaker
+///
+/// Returned by [`Client::circuit_state`](super::Client::circuit_state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent normally
+    Closed,
+    /// Requests are rejected with [`Error::CircuitOpen`] for `remaining`
+    Open { remaining: Duration },
+    /// The cooldown has elapsed; the next request is sent as a probe
+    HalfOpen,
+}
+
+/// Runtime state shared by all requests of a client
+#[derive(Debug)]
+pub(crate) struct Breaker {
+    config: CircuitBreaker,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    failures: u32,
+    first_failure: Option<Instant>,
+    opened_at: Option<Instant>,
+    probe_started: Option<Instant>,
+}
+
+impl Breaker {
+    pub(crate) fn new(config: CircuitBreaker) -> Self {
+        Self {
+            config,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    pub(crate) fn state(&self) -> CircuitState {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) => match self.config.cooldown.checked_sub(opened_at.elapsed()) {
+                Some(remaining) if !remaining.is_zero() => CircuitState::Open { remaining },
+                _ => CircuitState::HalfOpen,
+            },
+        }
+    }
+
+    /// Check whether a request may be sent
+    ///
+    /// While half-open only one probe is let through at a time. A probe that
+    /// never reports back (e.g. its future was dropped) is given up on after
+    /// another cooldown.
+    pub(crate) fn acquire(&self) -> Result<(), Error> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(opened_at) = state.opened_at else {
+            return Ok(());
+        };
+        if opened_at.elapsed() < self.config.cooldown {
+            return Err(Error::CircuitOpen);
+        }
+        match state.probe_started {
+            Some(started) if started.elapsed() < self.config.cooldown => Err(Error::CircuitOpen),
+            _ => {
+                state.probe_started = Some(Instant::now());
+                Ok(())
+            }
+        }
+    }
+
+    /// Record the outcome of a request that was sent
+    pub(crate) fn record(&self, status: Option<u16>) {
+        let failed = match status {
+            None => true,
+            Some(status) => status >= 500 || status == 429,
+        };
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        if !failed {
+            *state = BreakerState::default();
+            return;
+        }
+
+        let now = Instant::now();
+        if state.opened_at.is_some() {
+            // Without a probe running, this is a request that was already in
+            // flight when the circuit opened; it must not extend the cooldown
+            if state.probe_started.is_some() {
+                // Failed probe: stay open for another cooldown
+                state.opened_at = Some(now);
+                state.probe_started = None;
+            }
+            return;
+        }
+
+        match state.first_failure {
+            Some(first) if now.duration_since(first) <= self.config.window => {
+                state.failures += 1;
+            }
+            _ => {
+                state.first_failure = Some(now);
+                state.failures = 1;
+            }
+        }
+        if state.failures >= self.config.failure_threshold {
+            state.opened_at = Some(now);
+        }
+    }
+}
@@ -1,16 +1,52 @@
+use super::circuit::{Breaker, CircuitBreaker, CircuitState};
+#[cfg(feature = "coalesce")]
+use super::coalesce::InFlight;
+use super::concurrency::ConcurrencyLimit;
+use super::etags::{EtagStore, MAX_STORED_RESPONSES};
+use super::limits::*;
+use super::metrics::Metrics;
+use super::params::*;
 use crate::error::Error;
 use crate::types::*;
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use web_time::Instant;
 
 const DEFAULT_BASE_URL: &str = "https://open.faceit.com";
+const DEFAULT_API_VERSION: &str = "v4";
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// ELO change assumed per match by [`Client::get_elo_history`]
+const NOMINAL_ELO_CHANGE: i64 = 25;
+
+/// Number of match statistics fetched concurrently by [`Client::get_hub_report`]
+const STATS_CONCURRENCY: usize = 5;
+
+/// Maximum number of suggestions returned by [`Client::suggest_players`]
+const MAX_SUGGESTIONS: usize = 20;
+
+const API_KEY_ENV: &str = "FACEIT_API_KEY";
+const BASE_URL_ENV: &str = "FACEIT_BASE_URL";
+const TIMEOUT_SECS_ENV: &str = "FACEIT_TIMEOUT_SECS";
+
 /// Builder for creating a customized [`Client`]
 pub struct ClientBuilder {
     base_url: Option<String>,
+    api_version: Option<String>,
     api_key: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
     timeout: Option<Duration>,
     client_builder: reqwest::ClientBuilder,
+    language: Option<String>,
+    metrics: Option<Arc<dyn Metrics>>,
+    circuit_breaker: Option<CircuitBreaker>,
+    max_concurrency: Option<usize>,
+    conditional_requests: bool,
+    #[cfg(feature = "coalesce")]
+    coalesce_requests: bool,
 }
 
 impl ClientBuilder {
@@ -18,10 +54,62 @@ impl ClientBuilder {
     pub fn new() -> Self {
         Self {
             base_url: None,
+            api_version: None,
             api_key: None,
             timeout: Some(DEFAULT_TIMEOUT),
             client_builder: reqwest::Client::builder(),
+            language: None,
+            metrics: None,
+            circuit_breaker: None,
+            max_concurrency: None,
+            conditional_requests: false,
+            #[cfg(feature = "coalesce")]
+            coalesce_requests: false,
+        }
+    }
+
+    /// Create a builder configured from environment variables
+    ///
+    /// Reads the following variables:
+    /// - `FACEIT_API_KEY` - API key or access token (required)
+    /// - `FACEIT_BASE_URL` - Custom base URL (optional)
+    /// - `FACEIT_TIMEOUT_SECS` - Request timeout in seconds (optional)
+    ///
+    /// The returned builder can be further customized before calling [`build`](Self::build).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingParameter`] if `FACEIT_API_KEY` is not set.
+    /// Returns [`Error::InvalidParameter`] if `FACEIT_TIMEOUT_SECS` is not a valid number of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClientBuilder;
+    ///
+    /// let client = HttpClientBuilder::from_env()?.build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        let api_key =
+            var(API_KEY_ENV).ok_or_else(|| Error::MissingParameter(API_KEY_ENV.to_string()))?;
+        let mut builder = Self::new().api_key(api_key);
+
+        if let Some(base_url) = var(BASE_URL_ENV) {
+            builder = builder.base_url(base_url);
         }
+        if let Some(timeout) = var(TIMEOUT_SECS_ENV) {
+            let secs = timeout.trim().parse::<u64>().map_err(|_| {
+                Error::InvalidParameter(format!("{}={}", TIMEOUT_SECS_ENV, timeout))
+            })?;
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+
+        Ok(builder)
     }
 
     /// Set a custom base URL for the API
@@ -41,6 +129,51 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the Data API version (default: `"v4"`)
+    ///
+    /// Requests are sent to `{base_url}/data/{version}/...`. The version may
+    /// contain slashes to address a different path below `/data`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder()
+    ///     .api_version("v5")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = Some(version.into());
+        self
+    }
+
+    /// Request localized text by sending an `Accept-Language` header
+    ///
+    /// FACEIT translates some human-readable fields, such as game labels
+    /// ([`Game::long_label`](crate::types::Game::long_label)) and
+    /// descriptions of hubs and championships. IDs, statuses, stats keys
+    /// and nicknames are never translated, and fields without a translation
+    /// fall back to English.
+    ///
+    /// Accepts any `Accept-Language` value, e.g. `"de"` or `"pt-BR, pt;q=0.8"`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder()
+    ///     .language("de")
+    ///     .build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
     /// Set the API key or access token
     ///
     /// For the Data API, you can use either:
@@ -73,6 +206,9 @@ impl ClientBuilder {
 
     /// Set the request timeout
     ///
+    /// Requests that exceed it fail with [`Error::Timeout`]. Ignored on `wasm32` targets, where requests go through the browser's
+    /// `fetch` API and reqwest does not support client timeouts.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -86,7 +222,178 @@ impl ClientBuilder {
     /// ```
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = Some(timeout);
-        self.client_builder = self.client_builder.timeout(timeout);
+        self
+    }
+
+    /// Route requests through a proxy
+    ///
+    /// Can be called multiple times to add several proxies, e.g. separate
+    /// HTTP and HTTPS proxies.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder()
+    ///     .proxy(reqwest::Proxy::all("http://proxy.internal:3128")?)
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.client_builder = self.client_builder.proxy(proxy);
+        self
+    }
+
+    /// Disable all proxies, including any picked up from the environment
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder().no_proxy().build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn no_proxy(mut self) -> Self {
+        self.client_builder = self.client_builder.no_proxy();
+        self
+    }
+
+    /// Install hooks that observe every API request
+    ///
+    /// See [`Metrics`] for the callbacks that are invoked.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::{HttpClient, http::Metrics};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// struct LogMetrics;
+    ///
+    /// impl Metrics for LogMetrics {
+    ///     fn on_request_end(&self, path: &str, status: Option<u16>, elapsed: Duration) {
+    ///         println!("{} -> {:?} in {:?}", path, status, elapsed);
+    ///     }
+    /// }
+    ///
+    /// let client = HttpClient::builder().metrics(Arc::new(LogMetrics)).build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Stop sending requests while the API is failing
+    ///
+    /// After the configured number of consecutive failures, requests fail
+    /// immediately with [`Error::CircuitOpen`] until the cooldown has elapsed.
+    /// See [`CircuitBreaker`] for what counts as a failure, and
+    /// [`Client::circuit_state`] to report the state in health checks.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::{HttpClient, http::CircuitBreaker};
+    /// use std::time::Duration;
+    ///
+    /// let client = HttpClient::builder()
+    ///     .circuit_breaker(CircuitBreaker::new(5).cooldown(Duration::from_secs(60)))
+    ///     .build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Limit how many requests the client sends at once
+    ///
+    /// Requests beyond the limit wait until an earlier request has completed.
+    /// The limit is shared by everything using this client: the batch and
+    /// `get_all_*` helpers already fetch a few pages or profiles at a time,
+    /// and this caps the total when several of them run concurrently. A
+    /// limit of `0` is treated as `1`.
+    ///
+    /// This bounds requests in flight, not requests per second. The client
+    /// has no proactive rate limiter, so rate limit responses are still
+    /// returned as [`Error::Api`] with status `429`; lowering the limit makes
+    /// them less likely. Requests shared through
+    /// [`coalesce_requests`](Self::coalesce_requests) take a single slot, and
+    /// requests rejected by an open [circuit breaker](Self::circuit_breaker)
+    /// take none.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder().max_concurrency(4).build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.max_concurrency = Some(max);
+        self
+    }
+
+    /// Revalidate repeated requests with their ETag
+    ///
+    /// When enabled, the client remembers the `ETag` and body of every
+    /// successful response, keyed by the full request URL. Repeating the same
+    /// request sends `If-None-Match`, and a `304 Not Modified` answer is served
+    /// from the stored body. This suits rarely changing data such as games and
+    /// hub details.
+    ///
+    /// Up to 1024 responses are stored; once full, the response stored longest
+    /// ago is evicted. Avoid enabling this for clients that page through
+    /// large, ever-changing result sets, which would keep evicting the
+    /// responses worth revalidating.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder().conditional_requests(true).build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn conditional_requests(mut self, enabled: bool) -> Self {
+        self.conditional_requests = enabled;
+        self
+    }
+
+    /// Share one request between concurrent identical calls
+    ///
+    /// When enabled, a request for a URL that is already in flight does not
+    /// send a second HTTP request; it waits for the first one and decodes the
+    /// same response. This cuts load for hot resources such as a popular
+    /// match requested by many users at once. Requests are only shared while
+    /// in flight; nothing is cached afterwards.
+    ///
+    /// Timeouts and connection errors are reported to every waiting caller;
+    /// all but one of them see [`Error::Connection`] in place of
+    /// [`Error::Http`].
+    ///
+    /// Requires the `coalesce` feature, which is not supported on `wasm32`
+    /// targets: shared requests must be `Send`, and reqwest's browser futures
+    /// are not.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::builder().coalesce_requests(true).build()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    #[cfg(feature = "coalesce")]
+    pub fn coalesce_requests(mut self, enabled: bool) -> Self {
+        self.coalesce_requests = enabled;
         self
     }
 
@@ -103,6 +410,8 @@ impl ClientBuilder {
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the underlying HTTP client fails to build.
+    /// Returns [`Error::InvalidParameter`] if the [`language`](Self::language)
+    /// is not a valid header value.
     ///
     /// # Examples
     ///
@@ -115,20 +424,51 @@ impl ClientBuilder {
     /// # Ok::<(), faceit::error::Error>(())
     /// ```
     pub fn build(self) -> Result<Client, Error> {
-        let client = self
+        let language = self
+            .language
+            .map(|language| {
+                reqwest::header::HeaderValue::from_str(&language)
+                    .map_err(|_| Error::InvalidParameter(format!("language={}", language)))
+            })
+            .transpose()?;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let client_builder = self
             .client_builder
-            .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
-            .build()
-            .map_err(Error::Http)?;
+            .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT));
+        #[cfg(target_arch = "wasm32")]
+        let client_builder = self.client_builder;
+
+        let client = client_builder.build().map_err(Error::Http)?;
 
         let base_url = self
             .base_url
             .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let api_version = self.api_version.as_deref().unwrap_or(DEFAULT_API_VERSION);
+        let api_url = format!(
+            "{}/data/{}",
+            base_url.trim_end_matches('/'),
+            api_version.trim_matches('/')
+        );
 
         Ok(Client {
             reqwest_client: client,
             base_url,
+            api_url,
             api_key: self.api_key,
+            language,
+            metrics: self.metrics,
+            circuit_breaker: self
+                .circuit_breaker
+                .map(|config| Arc::new(Breaker::new(config))),
+            concurrency: self
+                .max_concurrency
+                .map(|max| Arc::new(ConcurrencyLimit::new(max))),
+            etags: self
+                .conditional_requests
+                .then(|| Mutex::new(EtagStore::new(MAX_STORED_RESPONSES))),
+            #[cfg(feature = "coalesce")]
+            in_flight: self.coalesce_requests.then(InFlight::default),
         })
     }
 }
@@ -143,7 +483,19 @@ impl Default for ClientBuilder {
 pub struct Client {
     reqwest_client: reqwest::Client,
     base_url: String,
+    /// `base_url` followed by the `/data/{version}` prefix
+    api_url: String,
     api_key: Option<String>,
+    /// `Accept-Language` header sent with every request
+    language: Option<reqwest::header::HeaderValue>,
+    metrics: Option<Arc<dyn Metrics>>,
+    circuit_breaker: Option<Arc<Breaker>>,
+    /// Limit on requests in flight, see [`ClientBuilder::max_concurrency`]
+    concurrency: Option<Arc<ConcurrencyLimit>>,
+    /// `ETag` and body of the last successful response per URL
+    etags: Option<Mutex<EtagStore>>,
+    #[cfg(feature = "coalesce")]
+    in_flight: Option<InFlight>,
 }
 
 impl Client {
@@ -154,8 +506,8 @@ impl Client {
     /// # Panics
     ///
     /// Panics if the underlying HTTP client fails to build. This should only happen
-    /// in exceptional circumstances (e.g., invalid TLS configuration). For more
-    /// control over error handling, use [`builder`](Self::builder) instead.
+    /// in exceptional circumstances (e.g., invalid TLS configuration). Use
+    /// [`try_new`](Self::try_new) to handle the error instead.
     ///
     /// # Examples
     ///
@@ -165,9 +517,27 @@ impl Client {
     /// let client = HttpClient::new();
     /// ```
     pub fn new() -> Self {
-        ClientBuilder::new()
-            .build()
-            .expect("Failed to create default client")
+        Self::try_new().expect("Failed to create default client")
+    }
+
+    /// Create a new client without authentication, returning an error on failure
+    ///
+    /// The fallible counterpart of [`new`](Self::new).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the underlying HTTP client fails to build.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::try_new()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn try_new() -> Result<Self, Error> {
+        ClientBuilder::new().build()
     }
 
     /// Create a builder for customizing the client configuration
@@ -191,6 +561,27 @@ impl Client {
         ClientBuilder::new()
     }
 
+    /// Create a client configured from environment variables
+    ///
+    /// See [`ClientBuilder::from_env`] for the variables that are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment is missing required variables or
+    /// contains invalid values, or if the underlying HTTP client fails to build.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::HttpClient;
+    ///
+    /// let client = HttpClient::from_env()?;
+    /// # Ok::<(), faceit::error::Error>(())
+    /// ```
+    pub fn from_env() -> Result<Self, Error> {
+        ClientBuilder::from_env()?.build()
+    }
+
     // ============================================================================
     // Player Methods
     // ============================================================================
@@ -198,6 +589,10 @@ impl Client {
     /// Get player details by player ID
     ///
     /// Returns a [`Player`](crate::types::Player) struct with player information.
+    /// The Data API has no field selection, so the full player object
+    /// (including all games and `friends_ids`) is always transferred.
+    /// To skip deserializing fields you do not need, use
+    /// [`get_as`](Self::get_as) with a narrower struct.
     ///
     /// # Arguments
     /// * `player_id` - The FACEIT player ID (UUID format)
@@ -220,12 +615,9 @@ impl Client {
     /// # }
     /// ```
     pub async fn get_player(&self, player_id: &str) -> Result<Player, Error> {
-        let url = format!("{}/data/v4/players/{}", self.base_url, player_id);
+        let url = format!("{}/players/{}", self.api_url, player_id);
         let request = self.reqwest_client.get(&url);
-        let request = self.add_api_key_header(request);
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
     /// Get player details from lookup (by nickname, game, or game_player_id)
@@ -259,7 +651,7 @@ impl Client {
         game: Option<&str>,
         game_player_id: Option<&str>,
     ) -> Result<Player, Error> {
-        let url = format!("{}/data/v4/players", self.base_url);
+        let url = format!("{}/players", self.api_url);
         let mut request = self.reqwest_client.get(&url);
 
         if let Some(nickname) = nickname {
@@ -272,9 +664,48 @@ impl Client {
             request = request.query(&[("game_player_id", game_player_id)]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
+    }
+
+    /// Get player details by any supported identifier
+    ///
+    /// Dispatches to [`get_player`](Self::get_player) for IDs and to
+    /// [`get_player_from_lookup`](Self::get_player_from_lookup) for nicknames
+    /// and game player IDs.
+    ///
+    /// # Arguments
+    /// * `player` - The identifier to look up
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::PlayerRef};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = client
+    ///     .player(&PlayerRef::Nickname("player_nickname".to_string()))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn player(&self, player: &PlayerRef) -> Result<Player, Error> {
+        match player {
+            PlayerRef::Id(player_id) => self.get_player(player_id).await,
+            PlayerRef::Nickname(nickname) => {
+                self.get_player_from_lookup(Some(nickname), None, None)
+                    .await
+            }
+            PlayerRef::GamePlayerId { id, game } => {
+                self.get_player_from_lookup(None, Some(game), Some(id))
+                    .await
+            }
+        }
     }
 
     /// Get player statistics for a specific game
@@ -304,15 +735,9 @@ impl Client {
         player_id: &str,
         game_id: &str,
     ) -> Result<PlayerStats, Error> {
-        let url = format!(
-            "{}/data/v4/players/{}/stats/{}",
-            self.base_url, player_id, game_id
-        );
+        let url = format!("{}/players/{}/stats/{}", self.api_url, player_id, game_id);
         let request = self.reqwest_client.get(&url);
-        let request = self.add_api_key_header(request);
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
     /// Get player match history
@@ -352,7 +777,7 @@ impl Client {
         offset: Option<i64>,
         limit: Option<i64>,
     ) -> Result<MatchHistoryList, Error> {
-        let url = format!("{}/data/v4/players/{}/history", self.base_url, player_id);
+        let url = format!("{}/players/{}/history", self.api_url, player_id);
         let mut request = self.reqwest_client.get(&url);
 
         request = request.query(&[("game", game)]);
@@ -369,22 +794,16 @@ impl Client {
             request = request.query(&[("limit", &limit.to_string())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Get player bans
-    ///
-    /// Returns a [`PlayerBansList`](crate::types::PlayerBansList) containing ban information.
+    /// Get player match history using a [`PlayerHistoryParams`] struct
     ///
-    /// # Arguments
-    /// * `player_id` - The FACEIT player ID
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// Equivalent to [`get_player_history`](Self::get_player_history) with named parameters.
     ///
     /// # Errors
     ///
+    /// Returns [`Error::MissingParameter`] if a required field of `params` is empty.
     /// Returns [`Error::Http`] if the HTTP request fails.
     /// Returns [`Error::Api`] if the API returns an error response.
     /// Returns [`Error::Json`] if the response cannot be parsed.
@@ -392,42 +811,41 @@ impl Client {
     /// # Examples
     ///
     /// ```no_run
-    /// # use faceit::HttpClient;
+    /// # use faceit::{HttpClient, http::PlayerHistoryParams};
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let bans = client.get_player_bans("player-id", Some(0), Some(20)).await?;
+    /// let params = PlayerHistoryParams::new("cs2").limit(50);
+    /// let history = client.get_player_history_with("player-id", &params).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_player_bans(
+    pub async fn get_player_history_with(
         &self,
         player_id: &str,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<PlayerBansList, Error> {
-        let url = format!("{}/data/v4/players/{}/bans", self.base_url, player_id);
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        params: &PlayerHistoryParams,
+    ) -> Result<MatchHistoryList, Error> {
+        params.validate()?;
+        self.get_player_history(
+            player_id,
+            &params.game,
+            params.from,
+            params.to,
+            params.offset,
+            params.limit,
+        )
+        .await
     }
 
-    /// Get player hubs
+    /// Get a player's entire match history within a time window
     ///
-    /// Returns a [`HubsList`](crate::types::HubsList) containing hub information.
+    /// Collects [`get_player_history_stream`](Self::get_player_history_stream);
+    /// each match is returned once, even if it appears on two pages.
     ///
     /// # Arguments
     /// * `player_id` - The FACEIT player ID
-    /// * `offset` - Optional offset for pagination (default: 0, max: 1000)
-    /// * `limit` - Optional limit for pagination (default: 50, max: 50)
+    /// * `game` - The game ID (required)
+    /// * `from` - Optional start timestamp (Unix time)
+    /// * `to` - Optional end timestamp (Unix time)
     ///
     /// # Errors
     ///
@@ -441,91 +859,117 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let hubs = client.get_player_hubs("player-id", Some(0), Some(50)).await?;
+    /// let season = client
+    ///     .get_player_history_all("player-id", "cs2", Some(1_700_000_000), Some(1_710_000_000))
+    ///     .await?;
+    /// println!("{} matches", season.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_player_hubs(
+    pub async fn get_player_history_all(
         &self,
         player_id: &str,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<HubsList, Error> {
-        let url = format!("{}/data/v4/players/{}/hubs", self.base_url, player_id);
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        game: &str,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<MatchHistory>, Error> {
+        self.get_player_history_stream(player_id, game, from, to)
+            .try_collect()
+            .await
     }
 
-    /// Get player teams
+    /// Stream a player's entire match history within a time window
     ///
-    /// Returns a [`TeamList`](crate::types::TeamList) containing team information.
+    /// Pages backwards through the window (newest first) by moving `to` to
+    /// just after the oldest match of each page, so matches that started in
+    /// the same second as a page boundary are not lost. Matches seen on more
+    /// than one page are yielded only once, keyed by `match_id`. Only one
+    /// page is held in memory at a time.
+    ///
+    /// The stream ends after the first error.
     ///
     /// # Arguments
     /// * `player_id` - The FACEIT player ID
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
-    ///
-    /// # Errors
-    ///
-    /// Returns [`Error::Http`] if the HTTP request fails.
-    /// Returns [`Error::Api`] if the API returns an error response.
-    /// Returns [`Error::Json`] if the response cannot be parsed.
+    /// * `game` - The game ID (required)
+    /// * `from` - Optional start timestamp (Unix time)
+    /// * `to` - Optional end timestamp (Unix time)
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use faceit::HttpClient;
+    /// use futures_util::{pin_mut, StreamExt};
+    ///
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let teams = client.get_player_teams("player-id", Some(0), Some(20)).await?;
+    /// let history = client.get_player_history_stream("player-id", "cs2", None, None);
+    /// pin_mut!(history);
+    /// while let Some(entry) = history.next().await {
+    ///     println!("{}", entry?.match_id);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_player_teams(
-        &self,
-        player_id: &str,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<TeamList, Error> {
-        let url = format!("{}/data/v4/players/{}/teams", self.base_url, player_id);
-        let mut request = self.reqwest_client.get(&url);
+    pub fn get_player_history_stream<'a>(
+        &'a self,
+        player_id: &'a str,
+        game: &'a str,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> impl Stream<Item = Result<MatchHistory, Error>> + 'a {
+        // State: (next `to`, match IDs seen so far, whether the stream is done)
+        futures_util::stream::unfold(
+            (to, HashSet::new(), false),
+            move |(to, mut seen, done)| async move {
+                if done {
+                    return None;
+                }
+                let page = match self
+                    .get_player_history(player_id, game, from, to, None, Some(MAX_PAGE_LIMIT))
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(e) => return Some((Err(e), (to, seen, true))),
+                };
 
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
+                let page_len = page.len() as i64;
+                let oldest = page.items.last().and_then(|history| history.started_at);
+                let new: Vec<MatchHistory> = page
+                    .into_iter()
+                    .filter(|history| seen.insert(history.match_id.clone()))
+                    .collect();
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+                // Stop on a short page, or when a full page held nothing new
+                // (more than a page of matches started in the same second)
+                let next_to = oldest.map(|oldest| oldest + 1);
+                let done = page_len < MAX_PAGE_LIMIT || new.is_empty() || next_to.is_none();
+                Some((Ok(new), (next_to, seen, done)))
+            },
+        )
+        .map_ok(|page| futures_util::stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
     }
 
-    /// Get player tournaments
+    /// Export a player's entire match history as newline-delimited JSON
     ///
-    /// Returns a [`TournamentsList`](crate::types::TournamentsList) containing tournament information.
+    /// Writes each match of [`get_player_history_stream`](Self::get_player_history_stream)
+    /// as one JSON line while paging, so no more than one page is held in
+    /// memory. Returns the number of matches written.
+    ///
+    /// Requires the `ndjson` feature.
     ///
     /// # Arguments
     /// * `player_id` - The FACEIT player ID
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// * `game` - The game ID (required)
+    /// * `writer` - Destination, e.g. a `tokio::fs::File`
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the HTTP request fails.
     /// Returns [`Error::Api`] if the API returns an error response.
     /// Returns [`Error::Json`] if the response cannot be parsed.
+    /// Returns [`Error::Io`] if writing fails. Lines written before an error
+    /// are left in `writer`.
     ///
     /// # Examples
     ///
@@ -533,44 +977,37 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let tournaments = client.get_player_tournaments("player-id", Some(0), Some(20)).await?;
+    /// let mut file = tokio::fs::File::create("history.ndjson").await?;
+    /// let written = client
+    ///     .export_player_history_ndjson("player-id", "cs2", &mut file)
+    ///     .await?;
+    /// println!("Exported {} matches", written);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_player_tournaments(
+    #[cfg(feature = "ndjson")]
+    pub async fn export_player_history_ndjson<W>(
         &self,
         player_id: &str,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<TournamentsList, Error> {
-        let url = format!(
-            "{}/data/v4/players/{}/tournaments",
-            self.base_url, player_id
-        );
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        game: &str,
+        writer: &mut W,
+    ) -> Result<u64, Error>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let history = self.get_player_history_stream(player_id, game, None, None);
+        crate::ndjson_export::write_stream(writer, history).await
     }
 
-    // ============================================================================
-    // Match Methods
-    // ============================================================================
-
-    /// Get match details
+    /// Get a player's most recent matches
     ///
-    /// Returns a [`Match`](crate::types::Match) struct with match information.
+    /// Pages through the player's match history (newest first) until `count`
+    /// matches have been collected or the history is exhausted.
     ///
     /// # Arguments
-    /// * `match_id` - The FACEIT match ID
+    /// * `player_id` - The FACEIT player ID
+    /// * `game` - The game ID (required)
+    /// * `count` - The number of matches to collect
     ///
     /// # Errors
     ///
@@ -584,25 +1021,60 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let match_details = client.get_match("match-id-here").await?;
+    /// let matches = client.get_recent_matches("player-id", "cs2", 30).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_match(&self, match_id: &str) -> Result<Match, Error> {
-        let url = format!("{}/data/v4/matches/{}", self.base_url, match_id);
-        let request = self.reqwest_client.get(&url);
-        let request = self.add_api_key_header(request);
+    pub async fn get_recent_matches(
+        &self,
+        player_id: &str,
+        game: &str,
+        count: u32,
+    ) -> Result<Vec<MatchHistory>, Error> {
+        let count = count as usize;
+        let mut matches = Vec::with_capacity(count);
+
+        while matches.len() < count {
+            let limit = MAX_PAGE_LIMIT.min((count - matches.len()) as i64);
+            let page = self
+                .get_player_history(
+                    player_id,
+                    game,
+                    None,
+                    None,
+                    Some(matches.len() as i64),
+                    Some(limit),
+                )
+                .await?;
+
+            let page_len = page.len() as i64;
+            matches.extend(page.items);
+            if page_len < limit {
+                break;
+            }
+        }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        matches.truncate(count);
+        Ok(matches)
     }
 
-    /// Get match statistics
+    /// Get an approximate ELO progression over a player's recent matches
     ///
-    /// Returns a [`MatchStats`](crate::types::MatchStats) struct with detailed match statistics.
+    /// The API does not report ELO per match, so the progression is derived
+    /// from the player's current ELO by walking back through their recent
+    /// matchmaking matches, assuming a nominal change of 25 ELO per win or
+    /// loss. FACEIT's real changes vary with the ELO difference between the
+    /// teams, so treat the result as a trend, not exact values. Hub and
+    /// championship matches do not affect ELO and are skipped, as are matches
+    /// without a known outcome.
+    ///
+    /// Returns `(finished_at, elo_after_match)` points, oldest first, or an
+    /// empty list if the player has no ELO for the game.
     ///
     /// # Arguments
-    /// * `match_id` - The FACEIT match ID
+    /// * `player_id` - The FACEIT player ID
+    /// * `game` - The game ID (e.g., "cs2")
+    /// * `count` - The number of recent matches to look at
     ///
     /// # Errors
     ///
@@ -616,26 +1088,39 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let stats = client.get_match_stats("match-id-here").await?;
+    /// for (at, elo) in client.get_elo_history("player-id", "cs2", 50).await? {
+    ///     println!("{}: ~{}", at, elo);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_match_stats(&self, match_id: &str) -> Result<MatchStats, Error> {
-        let url = format!("{}/data/v4/matches/{}/stats", self.base_url, match_id);
-        let request = self.reqwest_client.get(&url);
-        let request = self.add_api_key_header(request);
+    pub async fn get_elo_history(
+        &self,
+        player_id: &str,
+        game: &str,
+        count: u32,
+    ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, i64)>, Error> {
+        let (player, matches) = futures_util::future::try_join(
+            self.get_player(player_id),
+            self.get_recent_matches(player_id, game, count),
+        )
+        .await?;
+        let Some(elo) = player
+            .game_detail(game)
+            .and_then(|detail| detail.faceit_elo)
+        else {
+            return Ok(Vec::new());
+        };
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        Ok(estimate_elo_walk(player_id, elo, &matches))
     }
 
-    // ============================================================================
-    // Game Methods
-    // ============================================================================
-
-    /// Get all games
+    /// Get player bans
+    ///
+    /// Returns a [`PlayerBansList`](crate::types::PlayerBansList) containing ban information.
     ///
     /// # Arguments
+    /// * `player_id` - The FACEIT player ID
     /// * `offset` - Optional offset for pagination (default: 0)
     /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
@@ -651,16 +1136,17 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let games = client.get_all_games(Some(0), Some(20)).await?;
+    /// let bans = client.get_player_bans("player-id", Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_all_games(
+    pub async fn get_player_bans(
         &self,
+        player_id: &str,
         offset: Option<i64>,
         limit: Option<i64>,
-    ) -> Result<GamesList, Error> {
-        let url = format!("{}/data/v4/games", self.base_url);
+    ) -> Result<PlayerBansList, Error> {
+        let url = format!("{}/players/{}/bans", self.api_url, player_id);
         let mut request = self.reqwest_client.get(&url);
 
         if let Some(offset) = offset {
@@ -670,17 +1156,16 @@ impl Client {
             request = request.query(&[("limit", &limit.to_string())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Get game details
+    /// Get a player's bans that are currently in effect
     ///
-    /// Returns a [`Game`](crate::types::Game) struct with game information.
+    /// Pages through the player's whole ban history and keeps the bans that
+    /// are active now (see [`PlayerBan::is_active`](crate::types::PlayerBan::is_active)).
     ///
     /// # Arguments
-    /// * `game_id` - The game ID (e.g., "cs2", "csgo")
+    /// * `player_id` - The FACEIT player ID
     ///
     /// # Errors
     ///
@@ -694,25 +1179,44 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let game = client.get_game("cs2").await?;
+    /// let bans = client.get_active_player_bans("player-id").await?;
+    /// for ban in &bans {
+    ///     println!("{} banned in {}: {}", ban.nickname, ban.game, ban.reason);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_game(&self, game_id: &str) -> Result<Game, Error> {
-        let url = format!("{}/data/v4/games/{}", self.base_url, game_id);
-        let request = self.reqwest_client.get(&url);
-        let request = self.add_api_key_header(request);
+    pub async fn get_active_player_bans(&self, player_id: &str) -> Result<Vec<PlayerBan>, Error> {
+        let now = chrono::Utc::now();
+        let mut active = Vec::new();
+        for_each_page(
+            MAX_PAGE_LIMIT,
+            None,
+            |offset, limit| self.get_player_bans(player_id, Some(offset), Some(limit)),
+            |page| {
+                active.extend(page.into_iter().filter(|ban| ban.is_active(now)));
+                ControlFlow::Continue(())
+            },
+        )
+        .await?;
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        Ok(active)
     }
 
-    /// Get parent game details (for region-specific games)
+    /// Get player hubs
     ///
-    /// Returns a [`Game`](crate::types::Game) struct with parent game information.
+    /// Returns a [`HubsList`](crate::types::HubsList) containing hub information.
     ///
     /// # Arguments
-    /// * `game_id` - The game ID
+    /// * `player_id` - The FACEIT player ID
+    /// * `offset` - Optional offset for pagination (default: 0, max: 1000)
+    /// * `limit` - Optional limit for pagination (default: 50, max: 50)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
     ///
     /// # Examples
     ///
@@ -720,26 +1224,35 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let parent_game = client.get_parent_game("game-id").await?;
+    /// let hubs = client.get_player_hubs("player-id", Some(0), Some(50)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_parent_game(&self, game_id: &str) -> Result<Game, Error> {
-        let url = format!("{}/data/v4/games/{}/parent", self.base_url, game_id);
-        let request = self.reqwest_client.get(&url);
-        let request = self.add_api_key_header(request);
+    pub async fn get_player_hubs(
+        &self,
+        player_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<HubsList, Error> {
+        let url = format!("{}/players/{}/hubs", self.api_url, player_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Get game matchmakings
+    /// Get player teams
     ///
-    /// Returns a [`MatchmakingList`](crate::types::MatchmakingList) containing matchmaking information.
+    /// Returns a [`TeamList`](crate::types::TeamList) containing team information.
     ///
     /// # Arguments
-    /// * `game_id` - The game ID
-    /// * `region` - Optional region filter
+    /// * `player_id` - The FACEIT player ID
     /// * `offset` - Optional offset for pagination (default: 0)
     /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
@@ -755,23 +1268,19 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let matchmakings = client.get_game_matchmakings("cs2", Some("EU"), Some(0), Some(20)).await?;
+    /// let teams = client.get_player_teams("player-id", Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_game_matchmakings(
+    pub async fn get_player_teams(
         &self,
-        game_id: &str,
-        region: Option<&str>,
+        player_id: &str,
         offset: Option<i64>,
         limit: Option<i64>,
-    ) -> Result<MatchmakingList, Error> {
-        let url = format!("{}/data/v4/games/{}/matchmakings", self.base_url, game_id);
+    ) -> Result<TeamList, Error> {
+        let url = format!("{}/players/{}/teams", self.api_url, player_id);
         let mut request = self.reqwest_client.get(&url);
 
-        if let Some(region) = region {
-            request = request.query(&[("region", region)]);
-        }
         if let Some(offset) = offset {
             request = request.query(&[("offset", &offset.to_string())]);
         }
@@ -779,20 +1288,17 @@ impl Client {
             request = request.query(&[("limit", &limit.to_string())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    // ============================================================================
-    // Hub Methods
-    // ============================================================================
-
-    /// Get hub details
+    /// Get player tournaments
+    ///
+    /// Returns a [`TournamentsList`](crate::types::TournamentsList) containing tournament information.
     ///
     /// # Arguments
-    /// * `hub_id` - The hub ID
-    /// * `expanded` - Optional list of entities to expand (e.g., ["organizer", "game"])
+    /// * `player_id` - The FACEIT player ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
     /// # Errors
     ///
@@ -806,33 +1312,43 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let hub = client.get_hub("hub-id", None).await?;
+    /// let tournaments = client.get_player_tournaments("player-id", Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_hub(&self, hub_id: &str, expanded: Option<&[&str]>) -> Result<Hub, Error> {
-        let url = format!("{}/data/v4/hubs/{}", self.base_url, hub_id);
+    pub async fn get_player_tournaments(
+        &self,
+        player_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<TournamentsList, Error> {
+        let url = format!("{}/players/{}/tournaments", self.api_url, player_id);
         let mut request = self.reqwest_client.get(&url);
 
-        if let Some(expanded) = expanded {
-            let expanded_str = expanded.join(",");
-            request = request.query(&[("expanded", expanded_str.as_str())]);
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Get hub matches
+    // ============================================================================
+    // Match Methods
+    // ============================================================================
+
+    /// Get match details
     ///
-    /// Returns a [`MatchesList`](crate::types::MatchesList) containing match information.
+    /// Returns a [`Match`](crate::types::Match) struct with match information.
+    /// The Data API has no field selection, so the full match object is
+    /// always transferred.
+    /// To skip deserializing fields you do not need, use
+    /// [`get_as`](Self::get_as) with a narrower struct.
     ///
     /// # Arguments
-    /// * `hub_id` - The hub ID
-    /// * `match_type` - Optional match type filter ("all", "upcoming", "ongoing", "past")
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// * `match_id` - The FACEIT match ID
     ///
     /// # Errors
     ///
@@ -846,48 +1362,32 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let matches = client.get_hub_matches("hub-id", Some("all"), Some(0), Some(20)).await?;
+    /// let match_details = client.get_match("match-id-here").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_hub_matches(
-        &self,
-        hub_id: &str,
-        match_type: Option<&str>,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<MatchesList, Error> {
-        let url = format!("{}/data/v4/hubs/{}/matches", self.base_url, hub_id);
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(match_type) = match_type {
-            request = request.query(&[("type", match_type)]);
-        }
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+    pub async fn get_match(&self, match_id: &str) -> Result<Match, Error> {
+        let url = format!("{}/matches/{}", self.api_url, match_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
     }
 
-    /// Get hub members
+    /// Get match details, falling back to a match history entry
     ///
-    /// Returns a [`HubMembers`](crate::types::HubMembers) containing member information.
+    /// FACEIT no longer serves some old matches from the match endpoint even
+    /// though they still appear in players' histories. If
+    /// [`get_match`](Self::get_match) answers `404 Not Found`, a best-effort
+    /// [`Match`](crate::types::Match) is rebuilt from `entry` instead; see the
+    /// [`From<MatchHistory>`](crate::types::Match#impl-From%3CMatchHistory%3E-for-Match)
+    /// conversion for the fields that are missing in that case.
     ///
     /// # Arguments
-    /// * `hub_id` - The hub ID
-    /// * `offset` - Optional offset for pagination (default: 0, max: 1000)
-    /// * `limit` - Optional limit for pagination (default: 50, max: 50)
+    /// * `entry` - The match's entry in a player's history
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the HTTP request fails.
-    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Api`] if the API returns an error response other than `404`.
     /// Returns [`Error::Json`] if the response cannot be parsed.
     ///
     /// # Examples
@@ -896,39 +1396,27 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let members = client.get_hub_members("hub-id", Some(0), Some(50)).await?;
+    /// let history = client.get_player_history("player-id", "cs2", None, None, Some(0), Some(100)).await?;
+    /// for entry in &history {
+    ///     let match_data = client.get_match_or_history(entry).await?;
+    ///     println!("{}: {}", match_data.match_id, match_data.status);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_hub_members(
-        &self,
-        hub_id: &str,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<HubMembers, Error> {
-        let url = format!("{}/data/v4/hubs/{}/members", self.base_url, hub_id);
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
+    pub async fn get_match_or_history(&self, entry: &MatchHistory) -> Result<Match, Error> {
+        match self.get_match(&entry.match_id).await {
+            Err(e) if e.is_not_found() => Ok(Match::from(entry.clone())),
+            result => result,
         }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
     }
 
-    /// Get hub statistics
+    /// Get match statistics
     ///
-    /// Returns a [`HubStats`](crate::types::HubStats) containing hub statistics.
+    /// Returns a [`MatchStats`](crate::types::MatchStats) struct with detailed match statistics.
     ///
     /// # Arguments
-    /// * `hub_id` - The hub ID
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// * `match_id` - The FACEIT match ID
     ///
     /// # Errors
     ///
@@ -942,44 +1430,25 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let stats = client.get_hub_stats("hub-id", Some(0), Some(20)).await?;
+    /// let stats = client.get_match_stats("match-id-here").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_hub_stats(
-        &self,
-        hub_id: &str,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<HubStats, Error> {
-        let url = format!("{}/data/v4/hubs/{}/stats", self.base_url, hub_id);
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+    pub async fn get_match_stats(&self, match_id: &str) -> Result<MatchStats, Error> {
+        let url = format!("{}/matches/{}/stats", self.api_url, match_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
     }
 
     // ============================================================================
-    // Championship Methods
+    // Game Methods
     // ============================================================================
 
-    /// Get championships for a game
-    ///
-    /// Returns a [`ChampionshipsList`](crate::types::ChampionshipsList) containing championship information.
+    /// Get all games
     ///
     /// # Arguments
-    /// * `game` - The game ID (required)
-    /// * `championship_type` - Optional type filter ("all", "upcoming", "ongoing", "past")
     /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 10, max: 10)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
     /// # Errors
     ///
@@ -993,24 +1462,18 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let championships = client.get_championships("cs2", Some("all"), Some(0), Some(10)).await?;
+    /// let games = client.get_all_games(Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_championships(
+    pub async fn get_all_games(
         &self,
-        game: &str,
-        championship_type: Option<&str>,
         offset: Option<i64>,
         limit: Option<i64>,
-    ) -> Result<ChampionshipsList, Error> {
-        let url = format!("{}/data/v4/championships", self.base_url);
+    ) -> Result<GamesList, Error> {
+        let url = format!("{}/games", self.api_url);
         let mut request = self.reqwest_client.get(&url);
 
-        request = request.query(&[("game", game)]);
-        if let Some(championship_type) = championship_type {
-            request = request.query(&[("type", championship_type)]);
-        }
         if let Some(offset) = offset {
             request = request.query(&[("offset", &offset.to_string())]);
         }
@@ -1018,18 +1481,13 @@ impl Client {
             request = request.query(&[("limit", &limit.to_string())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Get championship details
-    ///
-    /// Returns a [`Championship`](crate::types::Championship) struct with championship information.
+    /// Get the full games catalog keyed by game ID
     ///
-    /// # Arguments
-    /// * `championship_id` - The championship ID
-    /// * `expanded` - Optional list of entities to expand (e.g., ["organizer", "game"])
+    /// Pages through [`get_all_games`](Self::get_all_games) until the catalog
+    /// is exhausted and indexes the results by `game_id`.
     ///
     /// # Errors
     ///
@@ -1043,40 +1501,29 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let championship = client.get_championship("championship-id", None).await?;
+    /// let games = client.get_all_games_map().await?;
+    /// if let Some(cs2) = games.get("cs2") {
+    ///     println!("{}", cs2.long_label);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_championship(
-        &self,
-        championship_id: &str,
-        expanded: Option<&[&str]>,
-    ) -> Result<Championship, Error> {
-        let url = format!(
-            "{}/data/v4/championships/{}",
-            self.base_url, championship_id
-        );
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(expanded) = expanded {
-            let expanded_str = expanded.join(",");
-            request = request.query(&[("expanded", expanded_str.as_str())]);
-        }
+    pub async fn get_all_games_map(&self) -> Result<HashMap<String, Game>, Error> {
+        let games = paginate(MAX_PAGE_LIMIT, None, |offset, limit| {
+            self.get_all_games(Some(offset), Some(limit))
+        })
+        .await?;
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        Ok(games
+            .into_iter()
+            .map(|game| (game.game_id.clone(), game))
+            .collect())
     }
 
-    /// Get championship matches
+    /// Get the full games catalog in FACEIT's display order
     ///
-    /// Returns a [`MatchesList`](crate::types::MatchesList) containing match information.
-    ///
-    /// # Arguments
-    /// * `championship_id` - The championship ID
-    /// * `match_type` - Optional match type filter ("all", "upcoming", "ongoing", "past")
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// Pages through [`get_all_games`](Self::get_all_games) until the catalog
+    /// is exhausted and sorts the result like [`GamesList::sorted_by_order`].
     ///
     /// # Errors
     ///
@@ -1090,52 +1537,28 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let matches = client.get_championship_matches("championship-id", Some("all"), Some(0), Some(20)).await?;
+    /// for game in client.get_all_games_sorted().await? {
+    ///     println!("{}", game.long_label);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_championship_matches(
-        &self,
-        championship_id: &str,
-        match_type: Option<&str>,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<MatchesList, Error> {
-        let url = format!(
-            "{}/data/v4/championships/{}/matches",
-            self.base_url, championship_id
-        );
-        let mut request = self.reqwest_client.get(&url);
-
-        if let Some(match_type) = match_type {
-            request = request.query(&[("type", match_type)]);
-        }
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
+    pub async fn get_all_games_sorted(&self) -> Result<Vec<Game>, Error> {
+        let mut games = paginate(MAX_PAGE_LIMIT, None, |offset, limit| {
+            self.get_all_games(Some(offset), Some(limit))
+        })
+        .await?;
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        crate::types::sort_by_display_order(&mut games, |game| game.order);
+        Ok(games)
     }
 
-    // ============================================================================
-    // Search Methods
-    // ============================================================================
-
-    /// Search for players
+    /// Get game details
     ///
-    /// Returns a [`UsersSearchList`](crate::types::UsersSearchList) containing search results.
+    /// Returns a [`Game`](crate::types::Game) struct with game information.
     ///
     /// # Arguments
-    /// * `nickname` - Player nickname to search for (required)
-    /// * `game` - Optional game ID filter
-    /// * `country` - Optional country code filter (ISO 3166-1)
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// * `game_id` - The game ID (e.g., "cs2", "csgo")
     ///
     /// # Errors
     ///
@@ -1149,47 +1572,65 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let results = client.search_players("player_name", Some("cs2"), None, Some(0), Some(20)).await?;
+    /// let game = client.get_game("cs2").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_players(
-        &self,
-        nickname: &str,
-        game: Option<&str>,
-        country: Option<&str>,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<UsersSearchList, Error> {
-        let url = format!("{}/data/v4/search/players", self.base_url);
-        let mut request = self.reqwest_client.get(&url);
+    pub async fn get_game(&self, game_id: &str) -> Result<Game, Error> {
+        let url = format!("{}/games/{}", self.api_url, game_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
+    }
 
-        request = request.query(&[("nickname", nickname)]);
-        if let Some(game) = game {
-            request = request.query(&[("game", game)]);
-        }
-        if let Some(country) = country {
-            request = request.query(&[("country", country)]);
-        }
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
+    /// Resolve a game ID, mapping common aliases to the ID the API uses
+    ///
+    /// For example, `"cs:go"` resolves to [`GameId::Csgo`](crate::types::GameId::Csgo).
+    /// Deprecated IDs are kept as-is, since historical data is still stored
+    /// under them; call [`GameId::current`](crate::types::GameId::current) to
+    /// map them to their replacement. This does not make a request; unknown IDs
+    /// are returned as [`GameId::Unknown`](crate::types::GameId::Unknown).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use faceit::{HttpClient, types::GameId};
+    /// assert_eq!(HttpClient::resolve_game("CS:GO"), GameId::Csgo);
+    /// assert_eq!(HttpClient::resolve_game("CS:GO").current(), GameId::Cs2);
+    /// ```
+    pub fn resolve_game(id: &str) -> GameId {
+        GameId::from(id.trim())
+    }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Get parent game details (for region-specific games)
+    ///
+    /// Returns a [`Game`](crate::types::Game) struct with parent game information.
+    ///
+    /// # Arguments
+    /// * `game_id` - The game ID
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let parent_game = client.get_parent_game("game-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_parent_game(&self, game_id: &str) -> Result<Game, Error> {
+        let url = format!("{}/games/{}/parent", self.api_url, game_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
     }
 
-    /// Search for teams
+    /// Get game matchmakings
     ///
-    /// Returns a [`TeamsSearchList`](crate::types::TeamsSearchList) containing search results.
+    /// Returns a [`MatchmakingList`](crate::types::MatchmakingList) containing matchmaking information.
     ///
     /// # Arguments
-    /// * `nickname` - Team nickname to search for (required)
-    /// * `game` - Optional game ID filter
+    /// * `game_id` - The game ID
+    /// * `region` - Optional region filter
     /// * `offset` - Optional offset for pagination (default: 0)
     /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
@@ -1205,23 +1646,22 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let results = client.search_teams("team_name", Some("cs2"), Some(0), Some(20)).await?;
+    /// let matchmakings = client.get_game_matchmakings("cs2", Some("EU"), Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_teams(
+    pub async fn get_game_matchmakings(
         &self,
-        nickname: &str,
-        game: Option<&str>,
+        game_id: &str,
+        region: Option<&str>,
         offset: Option<i64>,
         limit: Option<i64>,
-    ) -> Result<TeamsSearchList, Error> {
-        let url = format!("{}/data/v4/search/teams", self.base_url);
+    ) -> Result<MatchmakingList, Error> {
+        let url = format!("{}/games/{}/matchmakings", self.api_url, game_id);
         let mut request = self.reqwest_client.get(&url);
 
-        request = request.query(&[("nickname", nickname)]);
-        if let Some(game) = game {
-            request = request.query(&[("game", game)]);
+        if let Some(region) = region {
+            request = request.query(&[("region", region)]);
         }
         if let Some(offset) = offset {
             request = request.query(&[("offset", &offset.to_string())]);
@@ -1230,21 +1670,17 @@ impl Client {
             request = request.query(&[("limit", &limit.to_string())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Search for hubs
+    /// Get all matchmakings of a game
     ///
-    /// Returns a [`CompetitionsSearchList`](crate::types::CompetitionsSearchList) containing search results.
+    /// Pages through [`get_game_matchmakings`](Self::get_game_matchmakings)
+    /// until every matchmaking has been fetched.
     ///
     /// # Arguments
-    /// * `name` - Hub name to search for (required)
-    /// * `game` - Optional game ID filter
+    /// * `game_id` - The game ID
     /// * `region` - Optional region filter
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
     /// # Errors
     ///
@@ -1258,54 +1694,30 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let results = client.search_hubs("hub_name", Some("cs2"), Some("EU"), Some(0), Some(20)).await?;
+    /// let matchmakings = client.get_all_game_matchmakings("cs2", Some("EU")).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_hubs(
+    pub async fn get_all_game_matchmakings(
         &self,
-        name: &str,
-        game: Option<&str>,
+        game_id: &str,
         region: Option<&str>,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<CompetitionsSearchList, Error> {
-        let url = format!("{}/data/v4/search/hubs", self.base_url);
-        let mut request = self.reqwest_client.get(&url);
-
-        request = request.query(&[("name", name)]);
-        if let Some(game) = game {
-            request = request.query(&[("game", game)]);
-        }
-        if let Some(region) = region {
-            request = request.query(&[("region", region)]);
-        }
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
-
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+    ) -> Result<Vec<MatchmakingSlim>, Error> {
+        paginate(MAX_PAGE_LIMIT, None, |offset, limit| {
+            self.get_game_matchmakings(game_id, region, Some(offset), Some(limit))
+        })
+        .await
     }
 
     // ============================================================================
-    // Ranking Methods
+    // Hub Methods
     // ============================================================================
 
-    /// Get global ranking for a game and region
-    ///
-    /// Returns a [`GlobalRankingList`](crate::types::GlobalRankingList) containing ranking information.
+    /// Get hub details
     ///
     /// # Arguments
-    /// * `game_id` - The game ID
-    /// * `region` - The region (required)
-    /// * `country` - Optional country code filter (ISO 3166-1)
-    /// * `offset` - Optional offset for pagination (default: 0)
-    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    /// * `hub_id` - The hub ID
+    /// * `expanded` - Optional list of entities to expand (e.g., ["organizer", "game"])
     ///
     /// # Errors
     ///
@@ -1319,48 +1731,30 @@ impl Client {
     /// # use faceit::HttpClient;
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let ranking = client.get_global_ranking("cs2", "EU", None, Some(0), Some(20)).await?;
+    /// let hub = client.get_hub("hub-id", None).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_global_ranking(
-        &self,
-        game_id: &str,
-        region: &str,
-        country: Option<&str>,
-        offset: Option<i64>,
-        limit: Option<i64>,
-    ) -> Result<GlobalRankingList, Error> {
-        let url = format!(
-            "{}/data/v4/rankings/games/{}/regions/{}",
-            self.base_url, game_id, region
-        );
+    pub async fn get_hub(&self, hub_id: &str, expanded: Option<&[&str]>) -> Result<Hub, Error> {
+        let url = format!("{}/hubs/{}", self.api_url, hub_id);
         let mut request = self.reqwest_client.get(&url);
 
-        if let Some(country) = country {
-            request = request.query(&[("country", country)]);
-        }
-        if let Some(offset) = offset {
-            request = request.query(&[("offset", &offset.to_string())]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
+        if let Some(expanded) = expanded {
+            let expanded_str = expanded.join(",");
+            request = request.query(&[("expanded", expanded_str.as_str())]);
         }
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
-    /// Get player ranking in global ranking
+    /// Get hub matches
     ///
-    /// Returns a [`PlayerGlobalRanking`](crate::types::PlayerGlobalRanking) containing player ranking information.
+    /// Returns a [`MatchesList`](crate::types::MatchesList) containing match information.
     ///
     /// # Arguments
-    /// * `game_id` - The game ID
-    /// * `region` - The region (required)
-    /// * `player_id` - The player ID (required)
-    /// * `country` - Optional country code filter (ISO 3166-1)
+    /// * `hub_id` - The hub ID
+    /// * `match_type` - Optional [`MatchType`] filter (default: all)
+    /// * `offset` - Optional offset for pagination (default: 0)
     /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
     /// # Errors
@@ -1372,156 +1766,3814 @@ impl Client {
     /// # Examples
     ///
     /// ```no_run
-    /// # use faceit::HttpClient;
+    /// # use faceit::{HttpClient, http::MatchType};
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
-    /// let ranking = client.get_player_ranking("cs2", "EU", "player-id", None, Some(20)).await?;
+    /// let matches = client.get_hub_matches("hub-id", Some(MatchType::All), Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_player_ranking(
+    pub async fn get_hub_matches(
         &self,
-        game_id: &str,
-        region: &str,
-        player_id: &str,
-        country: Option<&str>,
+        hub_id: &str,
+        match_type: Option<MatchType>,
+        offset: Option<i64>,
         limit: Option<i64>,
-    ) -> Result<PlayerGlobalRanking, Error> {
-        let url = format!(
-            "{}/data/v4/rankings/games/{}/regions/{}/players/{}",
-            self.base_url, game_id, region, player_id
+    ) -> Result<MatchesList, Error> {
+        let url = format!("{}/hubs/{}/matches", self.api_url, hub_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(match_type) = match_type {
+            request = request.query(&[("type", match_type.as_str())]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Like [`get_hub_matches`](Self::get_hub_matches), with the match type as a string
+    ///
+    /// Accepts `"all"`, `"upcoming"`, `"ongoing"` or `"past"`, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] for any other match type, without
+    /// sending a request. Otherwise returns the same errors as
+    /// [`get_hub_matches`](Self::get_hub_matches).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let matches = client.get_hub_matches_str("hub-id", Some("past"), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_matches_str(
+        &self,
+        hub_id: &str,
+        match_type: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<MatchesList, Error> {
+        let match_type = match_type.map(str::parse).transpose()?;
+        self.get_hub_matches(hub_id, match_type, offset, limit)
+            .await
+    }
+
+    /// Get hub matches started within a time window
+    ///
+    /// The hub matches endpoint has no date filter, so this pages through the
+    /// hub's past matches (newest first) and keeps those whose `started_at`
+    /// falls in `[from, to)`. Paging stops once a whole page started before
+    /// `from`.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `from` - Start of the window (Unix time, inclusive)
+    /// * `to` - End of the window (Unix time, exclusive)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let week = client
+    ///     .get_hub_matches_between("hub-id", 1_700_000_000, 1_700_604_800)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_matches_between(
+        &self,
+        hub_id: &str,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<Match>, Error> {
+        let mut matches = Vec::new();
+        for_each_page(
+            MAX_PAGE_LIMIT,
+            None,
+            |offset, limit| {
+                self.get_hub_matches(hub_id, Some(MatchType::Past), Some(offset), Some(limit))
+            },
+            |page| {
+                let mut all_older = !page.is_empty();
+                for m in page {
+                    match m.started_at {
+                        Some(started) if started < from => continue,
+                        Some(started) if started < to => matches.push(m),
+                        _ => {}
+                    }
+                    all_older = false;
+                }
+
+                if all_older {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+        )
+        .await?;
+
+        Ok(matches)
+    }
+
+    /// Get a report of hub matches started within a time window
+    ///
+    /// Collects the matches with
+    /// [`get_hub_matches_between`](Self::get_hub_matches_between) and, if
+    /// `include_stats` is set, fetches the statistics of each match, five at a
+    /// time. Matches whose statistics are unavailable (e.g. cancelled matches,
+    /// for which the API answers `404 Not Found`) are kept with `stats` set to
+    /// `None`.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `from` - Start of the window (Unix time, inclusive)
+    /// * `to` - End of the window (Unix time, exclusive)
+    /// * `include_stats` - Whether to fetch statistics for each match
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    /// Fetching statistics fails the whole report on any error other than
+    /// `404 Not Found`, so an outage is not mistaken for matches without
+    /// statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let report = client
+    ///     .get_hub_report("hub-id", 1_700_000_000, 1_700_604_800, true)
+    ///     .await?;
+    /// for (player_id, totals) in report.player_totals() {
+    ///     println!("{}: {:?} kills", player_id, totals.total("Kills"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_report(
+        &self,
+        hub_id: &str,
+        from: i64,
+        to: i64,
+        include_stats: bool,
+    ) -> Result<HubReport, Error> {
+        let matches = self.get_hub_matches_between(hub_id, from, to).await?;
+
+        let matches = if include_stats {
+            futures_util::stream::iter(matches)
+                .map(|match_data| async move {
+                    let stats = match self.get_match_stats(&match_data.match_id).await {
+                        Ok(stats) => Some(stats),
+                        Err(e)
+                            if e.is_not_found() || matches!(e.inner(), Error::StatsNotReady(_)) =>
+                        {
+                            None
+                        }
+                        Err(e) => return Err(e),
+                    };
+                    Ok(HubReportMatch { match_data, stats })
+                })
+                .buffered(STATS_CONCURRENCY)
+                .try_collect()
+                .await?
+        } else {
+            matches
+                .into_iter()
+                .map(|match_data| HubReportMatch {
+                    match_data,
+                    stats: None,
+                })
+                .collect()
+        };
+
+        Ok(HubReport {
+            hub_id: hub_id.to_string(),
+            from,
+            to,
+            matches,
+        })
+    }
+
+    /// Get hub members
+    ///
+    /// Returns a [`HubMembers`](crate::types::HubMembers) containing member information.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `offset` - Optional offset for pagination (default: 0, max: 1000)
+    /// * `limit` - Optional limit for pagination (default: 50, max: 50)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let members = client.get_hub_members("hub-id", Some(0), Some(50)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_members(
+        &self,
+        hub_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<HubMembers, Error> {
+        let url = format!("{}/hubs/{}/members", self.api_url, hub_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get all hub members
+    ///
+    /// Pages through [`get_hub_members`](Self::get_hub_members). The members
+    /// endpoint only allows offsets up to 1000, so at most 1050 members are
+    /// returned.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let members = client.get_all_hub_members("hub-id").await?;
+    /// println!("{} members", members.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_hub_members(&self, hub_id: &str) -> Result<Vec<HubUser>, Error> {
+        paginate(MAX_HUB_PAGE_LIMIT, Some(MAX_HUB_OFFSET), |offset, limit| {
+            self.get_hub_members(hub_id, Some(offset), Some(limit))
+        })
+        .await
+    }
+
+    /// Get hub roles
+    ///
+    /// Returns a [`HubRolesList`](crate::types::HubRolesList) with the roles
+    /// that [`HubUser::roles`](crate::types::HubUser::roles) refer to by ID.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 50)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let roles = client.get_hub_roles("hub-id", None, Some(50)).await?;
+    /// for role in &roles {
+    ///     println!("{}: {}", role.role_id, role.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_roles(
+        &self,
+        hub_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<HubRolesList, Error> {
+        let url = format!("{}/hubs/{}/roles", self.api_url, hub_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get all of a hub's roles
+    ///
+    /// Pages through [`get_hub_roles`](Self::get_hub_roles).
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let roles = client.get_all_hub_roles("hub-id").await?;
+    /// println!("{} roles", roles.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_hub_roles(&self, hub_id: &str) -> Result<Vec<HubRole>, Error> {
+        paginate(MAX_HUB_ROLES_PAGE_LIMIT, None, |offset, limit| {
+            self.get_hub_roles(hub_id, Some(offset), Some(limit))
+        })
+        .await
+    }
+
+    /// Check whether a player is a member of a hub
+    ///
+    /// Pages through the player's hubs rather than the hub's members, since
+    /// players belong to far fewer hubs than large hubs have members, and stops
+    /// as soon as the hub is found. The hubs endpoint only allows offsets up to
+    /// 1000, so at most 1050 of the player's hubs are checked.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `player_id` - The FACEIT player ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// if client.is_hub_member("hub-id", "player-id").await? {
+    ///     println!("Player is a member");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_hub_member(&self, hub_id: &str, player_id: &str) -> Result<bool, Error> {
+        let mut found = false;
+        for_each_page(
+            MAX_HUB_PAGE_LIMIT,
+            Some(MAX_HUB_OFFSET),
+            |offset, limit| self.get_player_hubs(player_id, Some(offset), Some(limit)),
+            |page| {
+                found = page.iter().any(|hub| hub.hub_id == hub_id);
+                if found {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+        )
+        .await?;
+
+        Ok(found)
+    }
+
+    /// Get hub statistics
+    ///
+    /// Returns a [`HubStats`](crate::types::HubStats) containing hub statistics.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let stats = client.get_hub_stats("hub-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_stats(
+        &self,
+        hub_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<HubStats, Error> {
+        let url = format!("{}/hubs/{}/stats", self.api_url, hub_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // Championship Methods
+    // ============================================================================
+
+    /// Get championships for a game
+    ///
+    /// Returns a [`ChampionshipsList`](crate::types::ChampionshipsList) containing championship information.
+    ///
+    /// # Arguments
+    /// * `game` - The game ID (required)
+    /// * `championship_type` - Optional type filter ("all", "upcoming", "ongoing", "past")
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 10, max: 10)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championships = client.get_championships("cs2", Some("all"), Some(0), Some(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_championships(
+        &self,
+        game: &str,
+        championship_type: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<ChampionshipsList, Error> {
+        let url = format!("{}/championships", self.api_url);
+        let mut request = self.reqwest_client.get(&url);
+
+        request = request.query(&[("game", game)]);
+        if let Some(championship_type) = championship_type {
+            request = request.query(&[("type", championship_type)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get championship details
+    ///
+    /// Returns a [`Championship`](crate::types::Championship) struct with championship information.
+    ///
+    /// # Arguments
+    /// * `championship_id` - The championship ID
+    /// * `expanded` - Optional list of entities to expand (e.g., ["organizer", "game"])
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championship = client.get_championship("championship-id", None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_championship(
+        &self,
+        championship_id: &str,
+        expanded: Option<&[&str]>,
+    ) -> Result<Championship, Error> {
+        let url = format!("{}/championships/{}", self.api_url, championship_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(expanded) = expanded {
+            let expanded_str = expanded.join(",");
+            request = request.query(&[("expanded", expanded_str.as_str())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get championship matches
+    ///
+    /// Returns a [`MatchesList`](crate::types::MatchesList) containing match information.
+    ///
+    /// # Arguments
+    /// * `championship_id` - The championship ID
+    /// * `match_type` - Optional [`MatchType`] filter (default: all)
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::MatchType};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let matches = client.get_championship_matches("championship-id", Some(MatchType::All), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_championship_matches(
+        &self,
+        championship_id: &str,
+        match_type: Option<MatchType>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<MatchesList, Error> {
+        let url = format!("{}/championships/{}/matches", self.api_url, championship_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(match_type) = match_type {
+            request = request.query(&[("type", match_type.as_str())]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Like [`get_championship_matches`](Self::get_championship_matches), with the match type as a string
+    ///
+    /// Accepts `"all"`, `"upcoming"`, `"ongoing"` or `"past"`, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] for any other match type, without
+    /// sending a request. Otherwise returns the same errors as
+    /// [`get_championship_matches`](Self::get_championship_matches).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let matches = client.get_championship_matches_str("championship-id", Some("past"), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_championship_matches_str(
+        &self,
+        championship_id: &str,
+        match_type: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<MatchesList, Error> {
+        let match_type = match_type.map(str::parse).transpose()?;
+        self.get_championship_matches(championship_id, match_type, offset, limit)
+            .await
+    }
+
+    /// Get all matches of a championship
+    ///
+    /// Pages through [`get_championship_matches`](Self::get_championship_matches)
+    /// until every match has been fetched, keeping the order the API returns.
+    ///
+    /// # Arguments
+    /// * `championship_id` - The championship ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let matches = client.get_all_championship_matches("championship-id").await?;
+    /// println!("{} matches", matches.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_championship_matches(
+        &self,
+        championship_id: &str,
+    ) -> Result<Vec<Match>, Error> {
+        paginate(MAX_PAGE_LIMIT, None, |offset, limit| {
+            self.get_championship_matches(
+                championship_id,
+                Some(MatchType::All),
+                Some(offset),
+                Some(limit),
+            )
+        })
+        .await
+    }
+
+    /// Get teams subscribed to a championship
+    ///
+    /// Returns a [`ChampionshipSubscriptionsList`](crate::types::ChampionshipSubscriptionsList)
+    /// with a team summary and roster per subscription.
+    ///
+    /// # Arguments
+    /// * `championship_id` - The championship ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 10, max: 10)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let subscriptions = client.get_championship_subscriptions("championship-id", Some(0), Some(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_championship_subscriptions(
+        &self,
+        championship_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<ChampionshipSubscriptionsList, Error> {
+        let url = format!(
+            "{}/championships/{}/subscriptions",
+            self.api_url, championship_id
+        );
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get all teams subscribed to a championship
+    ///
+    /// Pages through [`get_championship_subscriptions`](Self::get_championship_subscriptions)
+    /// until every subscription has been fetched.
+    ///
+    /// # Arguments
+    /// * `championship_id` - The championship ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let subscriptions = client.get_all_championship_subscriptions("championship-id").await?;
+    /// println!("{} teams signed up", subscriptions.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_championship_subscriptions(
+        &self,
+        championship_id: &str,
+    ) -> Result<Vec<ChampionshipSubscription>, Error> {
+        paginate(MAX_SUBSCRIPTIONS_PAGE_LIMIT, None, |offset, limit| {
+            self.get_championship_subscriptions(championship_id, Some(offset), Some(limit))
+        })
+        .await
+    }
+
+    // ============================================================================
+    // Team Methods
+    // ============================================================================
+
+    /// Get team details
+    ///
+    /// Returns a [`Team`](crate::types::Team) with full team information, including members.
+    ///
+    /// # Arguments
+    /// * `team_id` - The team ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let team = client.get_team("team-id").await?;
+    /// println!("Team: {}", team.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_team(&self, team_id: &str) -> Result<Team, Error> {
+        let url = format!("{}/teams/{}", self.api_url, team_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // Organizer Methods
+    // ============================================================================
+
+    /// Get organizer details
+    ///
+    /// Returns an [`Organizer`](crate::types::Organizer) with organizer information.
+    ///
+    /// # Arguments
+    /// * `organizer_id` - The organizer ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let organizer = client.get_organizer("organizer-id").await?;
+    /// println!("Organizer: {}", organizer.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_organizer(&self, organizer_id: &str) -> Result<Organizer, Error> {
+        let url = format!("{}/organizers/{}", self.api_url, organizer_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
+    }
+
+    /// Get hubs run by an organizer
+    ///
+    /// Returns a [`HubsList`](crate::types::HubsList) containing hub information.
+    ///
+    /// # Arguments
+    /// * `organizer_id` - The organizer ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 50)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hubs = client.get_organizer_hubs("organizer-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_organizer_hubs(
+        &self,
+        organizer_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<HubsList, Error> {
+        let url = format!("{}/organizers/{}/hubs", self.api_url, organizer_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get championships run by an organizer
+    ///
+    /// Returns a [`ChampionshipsList`](crate::types::ChampionshipsList) containing championship information.
+    ///
+    /// # Arguments
+    /// * `organizer_id` - The organizer ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championships = client.get_organizer_championships("organizer-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_organizer_championships(
+        &self,
+        organizer_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<ChampionshipsList, Error> {
+        let url = format!("{}/organizers/{}/championships", self.api_url, organizer_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get an organizer together with its hubs and active championships
+    ///
+    /// Fetches the organizer, the first page of its hubs and the first page of
+    /// its championships concurrently. Championships that are over (see
+    /// [`ChampionshipStatus::is_over`]) are left out.
+    ///
+    /// # Arguments
+    /// * `organizer_id` - The organizer ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let overview = client.get_organizer_overview("organizer-id").await?;
+    /// println!(
+    ///     "{}: {} hubs, {} active championships",
+    ///     overview.organizer.name,
+    ///     overview.hubs.len(),
+    ///     overview.championships.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_organizer_overview(
+        &self,
+        organizer_id: &str,
+    ) -> Result<OrganizerOverview, Error> {
+        let (organizer, hubs, championships) = futures_util::future::try_join3(
+            self.get_organizer(organizer_id),
+            self.get_organizer_hubs(organizer_id, None, Some(MAX_HUB_PAGE_LIMIT)),
+            self.get_organizer_championships(organizer_id, None, Some(MAX_PAGE_LIMIT)),
+        )
+        .await?;
+
+        Ok(OrganizerOverview {
+            organizer,
+            hubs,
+            championships: championships
+                .into_iter()
+                .filter(|championship| !championship.status_kind().is_over())
+                .collect(),
+        })
+    }
+
+    // ============================================================================
+    // Tournament Methods
+    // ============================================================================
+
+    /// Get tournament details
+    ///
+    /// Returns a [`Tournament`](crate::types::Tournament) with full tournament information.
+    /// FACEIT has deprecated tournaments in favour of championships, but the
+    /// endpoint still serves the tournaments listed by
+    /// [`get_player_tournaments`](Self::get_player_tournaments).
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament ID
+    /// * `expanded` - Optional list of entities to expand (e.g., ["organizer", "game"])
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let tournament = client.get_tournament("tournament-id", None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tournament(
+        &self,
+        tournament_id: &str,
+        expanded: Option<&[&str]>,
+    ) -> Result<Tournament, Error> {
+        let url = format!("{}/tournaments/{}", self.api_url, tournament_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(expanded) = expanded {
+            let expanded_str = expanded.join(",");
+            request = request.query(&[("expanded", expanded_str.as_str())]);
+        }
+
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // Search Methods
+    // ============================================================================
+
+    /// Search for players
+    ///
+    /// Returns a [`UsersSearchList`](crate::types::UsersSearchList) containing search results.
+    ///
+    /// # Arguments
+    /// * `nickname` - Player nickname to search for (required)
+    /// * `game` - Optional game ID filter
+    /// * `country` - Optional country code filter (ISO 3166-1 alpha-2, e.g. "GB")
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    /// Returns [`Error::InvalidCountry`] if `country` is not an ISO 3166-1 alpha-2 code.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let results = client.search_players("player_name", Some("cs2"), None, Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_players(
+        &self,
+        nickname: &str,
+        game: Option<&str>,
+        country: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<UsersSearchList, Error> {
+        let url = format!("{}/search/players", self.api_url);
+        let mut request = self.reqwest_client.get(&url);
+
+        request = request.query(&[("nickname", nickname)]);
+        if let Some(game) = game {
+            request = request.query(&[("game", game)]);
+        }
+        if let Some(country) = country {
+            validate_country(country)?;
+            request = request.query(&[("country", country)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Search for players using a [`SearchPlayersParams`] struct
+    ///
+    /// Equivalent to [`search_players`](Self::search_players) with named parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingParameter`] if a required field of `params` is empty.
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::SearchPlayersParams};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let params = SearchPlayersParams::new("player_name").game("cs2");
+    /// let results = client.search_players_with(&params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_players_with(
+        &self,
+        params: &SearchPlayersParams,
+    ) -> Result<UsersSearchList, Error> {
+        params.validate()?;
+        self.search_players(
+            &params.nickname,
+            params.game.as_deref(),
+            params.country.as_deref(),
+            params.offset,
+            params.limit,
+        )
+        .await
+    }
+
+    /// Suggest players whose nickname matches what has been typed so far
+    ///
+    /// Intended for search-as-you-type boxes: returns only the ID, nickname
+    /// and avatar of the first `limit` matches (at most 20). A prefix that is
+    /// empty after trimming returns no suggestions without sending a request.
+    ///
+    /// Each call is a regular [`search_players`](Self::search_players)
+    /// request, so it counts against the rate limit. Debounce keystrokes in
+    /// the caller, and enable
+    /// [`coalesce_requests`](super::ClientBuilder::coalesce_requests) so
+    /// identical lookups in flight at the same time share one request.
+    ///
+    /// # Arguments
+    /// * `prefix` - The nickname typed so far
+    /// * `game` - Optional game ID filter
+    /// * `limit` - Maximum number of suggestions
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// for suggestion in client.suggest_players("s1m", Some("cs2"), 5).await? {
+    ///     println!("{} ({})", suggestion.nickname, suggestion.player_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn suggest_players(
+        &self,
+        prefix: &str,
+        game: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<PlayerSuggestion>, Error> {
+        let prefix = prefix.trim();
+        let limit = limit.min(MAX_SUGGESTIONS);
+        if prefix.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let results = self
+            .search_players(prefix, game, None, None, Some(limit as i64))
+            .await?;
+
+        Ok(results
+            .items
+            .into_iter()
+            .take(limit)
+            .map(PlayerSuggestion::from)
+            .collect())
+    }
+
+    /// Search for teams
+    ///
+    /// Returns a [`TeamsSearchList`](crate::types::TeamsSearchList) containing search results.
+    ///
+    /// # Arguments
+    /// * `nickname` - Team nickname to search for (required)
+    /// * `game` - Optional game ID filter
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let results = client.search_teams("team_name", Some("cs2"), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_teams(
+        &self,
+        nickname: &str,
+        game: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<TeamsSearchList, Error> {
+        let url = format!("{}/search/teams", self.api_url);
+        let mut request = self.reqwest_client.get(&url);
+
+        request = request.query(&[("nickname", nickname)]);
+        if let Some(game) = game {
+            request = request.query(&[("game", game)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Search for teams using a [`SearchTeamsParams`] struct
+    ///
+    /// Equivalent to [`search_teams`](Self::search_teams) with named parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingParameter`] if a required field of `params` is empty.
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::SearchTeamsParams};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let params = SearchTeamsParams::new("team_name").game("cs2");
+    /// let results = client.search_teams_with(&params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_teams_with(
+        &self,
+        params: &SearchTeamsParams,
+    ) -> Result<TeamsSearchList, Error> {
+        params.validate()?;
+        self.search_teams(
+            &params.nickname,
+            params.game.as_deref(),
+            params.offset,
+            params.limit,
+        )
+        .await
+    }
+
+    /// Search for hubs
+    ///
+    /// Returns a [`CompetitionsSearchList`](crate::types::CompetitionsSearchList) containing search results.
+    ///
+    /// # Arguments
+    /// * `name` - Hub name to search for (required)
+    /// * `game` - Optional game ID filter
+    /// * `region` - Optional region filter
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let results = client.search_hubs("hub_name", Some("cs2"), Some("EU"), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_hubs(
+        &self,
+        name: &str,
+        game: Option<&str>,
+        region: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<CompetitionsSearchList, Error> {
+        let url = format!("{}/search/hubs", self.api_url);
+        let mut request = self.reqwest_client.get(&url);
+
+        request = request.query(&[("name", name)]);
+        if let Some(game) = game {
+            request = request.query(&[("game", game)]);
+        }
+        if let Some(region) = region {
+            request = request.query(&[("region", region)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Search for hubs using a [`SearchHubsParams`] struct
+    ///
+    /// Equivalent to [`search_hubs`](Self::search_hubs) with named parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingParameter`] if a required field of `params` is empty.
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::SearchHubsParams};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let params = SearchHubsParams::new("hub_name").region("EU");
+    /// let results = client.search_hubs_with(&params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_hubs_with(
+        &self,
+        params: &SearchHubsParams,
+    ) -> Result<CompetitionsSearchList, Error> {
+        params.validate()?;
+        self.search_hubs(
+            &params.name,
+            params.game.as_deref(),
+            params.region.as_deref(),
+            params.offset,
+            params.limit,
+        )
+        .await
+    }
+
+    /// Search players, teams and hubs at once
+    ///
+    /// Runs [`search_players`](Self::search_players),
+    /// [`search_teams`](Self::search_teams) and
+    /// [`search_hubs`](Self::search_hubs) concurrently with their default
+    /// filters and pagination, and combines the results.
+    ///
+    /// # Arguments
+    /// * `query` - Nickname or name to search for
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by any of the three searches.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let results = client.search("navi").await?;
+    /// println!(
+    ///     "{} players, {} teams, {} hubs",
+    ///     results.players.len(),
+    ///     results.teams.len(),
+    ///     results.hubs.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(&self, query: &str) -> Result<SearchResults, Error> {
+        let (players, teams, hubs) = futures_util::future::try_join3(
+            self.search_players(query, None, None, None, None),
+            self.search_teams(query, None, None, None),
+            self.search_hubs(query, None, None, None, None),
+        )
+        .await?;
+
+        Ok(SearchResults {
+            players,
+            teams,
+            hubs,
+        })
+    }
+
+    // ============================================================================
+    // Ranking Methods
+    // ============================================================================
+
+    /// Get global ranking for a game and region
+    ///
+    /// Returns a [`GlobalRankingList`](crate::types::GlobalRankingList) containing ranking information.
+    ///
+    /// # Arguments
+    /// * `game_id` - The game ID
+    /// * `region` - The region (required)
+    /// * `country` - Optional country code filter (ISO 3166-1 alpha-2, e.g. "GB")
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    /// Returns [`Error::InvalidCountry`] if `country` is not an ISO 3166-1 alpha-2 code.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let ranking = client.get_global_ranking("cs2", "EU", None, Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_global_ranking(
+        &self,
+        game_id: &str,
+        region: &str,
+        country: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<GlobalRankingList, Error> {
+        let url = format!(
+            "{}/rankings/games/{}/regions/{}",
+            self.api_url, game_id, region
+        );
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(country) = country {
+            validate_country(country)?;
+            request = request.query(&[("country", country)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get global ranking using a [`GlobalRankingParams`] struct
+    ///
+    /// Equivalent to [`get_global_ranking`](Self::get_global_ranking) with named parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingParameter`] if a required field of `params` is empty.
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::GlobalRankingParams};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let params = GlobalRankingParams::new("cs2", "EU").limit(50);
+    /// let ranking = client.get_global_ranking_with(&params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_global_ranking_with(
+        &self,
+        params: &GlobalRankingParams,
+    ) -> Result<GlobalRankingList, Error> {
+        params.validate()?;
+        self.get_global_ranking(
+            &params.game_id,
+            &params.region,
+            params.country.as_deref(),
+            params.offset,
+            params.limit,
+        )
+        .await
+    }
+
+    /// Get player ranking in global ranking
+    ///
+    /// Returns a [`PlayerGlobalRanking`](crate::types::PlayerGlobalRanking) containing player ranking information.
+    ///
+    /// # Arguments
+    /// * `game_id` - The game ID
+    /// * `region` - The region (required)
+    /// * `player_id` - The player ID (required)
+    /// * `country` - Optional country code filter (ISO 3166-1 alpha-2, e.g. "GB")
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    /// Returns [`Error::InvalidCountry`] if `country` is not an ISO 3166-1 alpha-2 code.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let ranking = client.get_player_ranking("cs2", "EU", "player-id", None, Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_player_ranking(
+        &self,
+        game_id: &str,
+        region: &str,
+        player_id: &str,
+        country: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<PlayerGlobalRanking, Error> {
+        let url = format!(
+            "{}/rankings/games/{}/regions/{}/players/{}",
+            self.api_url, game_id, region, player_id
+        );
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(country) = country {
+            validate_country(country)?;
+            request = request.query(&[("country", country)]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // League Methods
+    // ============================================================================
+
+    /// Get league details
+    ///
+    /// Returns a [`League`](crate::types::League) struct with league information,
+    /// including a summary of its seasons.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league ID (see [`Matchmaking::league_id`](crate::types::Matchmaking::league_id))
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let league = client.get_league("league-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league(&self, league_id: &str) -> Result<League, Error> {
+        let url = format!("{}/leagues/{}", self.api_url, league_id);
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
+    }
+
+    /// Get league season details
+    ///
+    /// Returns a [`LeagueSeason`](crate::types::LeagueSeason) struct with the season's divisions.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league ID
+    /// * `season_id` - The season ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let season = client.get_league_season("league-id", "season-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league_season(
+        &self,
+        league_id: &str,
+        season_id: &str,
+    ) -> Result<LeagueSeason, Error> {
+        let url = format!(
+            "{}/leagues/{}/seasons/{}",
+            self.api_url, league_id, season_id
+        );
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
+    }
+
+    /// Get a player's standing in a league season
+    ///
+    /// Returns a [`PlayerLeagueSeason`](crate::types::PlayerLeagueSeason) struct.
+    ///
+    /// # Arguments
+    /// * `league_id` - The league ID
+    /// * `season_id` - The season ID
+    /// * `player_id` - The FACEIT player ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let standing = client
+    ///     .get_player_league_season("league-id", "season-id", "player-id")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_player_league_season(
+        &self,
+        league_id: &str,
+        season_id: &str,
+        player_id: &str,
+    ) -> Result<PlayerLeagueSeason, Error> {
+        let url = format!(
+            "{}/leagues/{}/seasons/{}/players/{}",
+            self.api_url, league_id, season_id, player_id
+        );
+        let request = self.reqwest_client.get(&url);
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // Leaderboard Methods
+    // ============================================================================
+
+    /// Get the leaderboards of a hub
+    ///
+    /// Returns a [`LeaderboardsList`](crate::types::LeaderboardsList) with the
+    /// hub's leaderboards, such as one per season.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let leaderboards = client.get_hub_leaderboards("hub-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_leaderboards(
+        &self,
+        hub_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<LeaderboardsList, Error> {
+        let url = format!("{}/leaderboards/hubs/{}", self.api_url, hub_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get the all-time standings of a hub
+    ///
+    /// Returns a [`LeaderboardRanking`](crate::types::LeaderboardRanking)
+    /// for the hub's general leaderboard.
+    ///
+    /// # Arguments
+    /// * `hub_id` - The hub ID
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let standings = client.get_hub_general_leaderboard("hub-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_hub_general_leaderboard(
+        &self,
+        hub_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<LeaderboardRanking, Error> {
+        let url = format!("{}/leaderboards/hubs/{}/general", self.api_url, hub_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    /// Get the standings of a leaderboard
+    ///
+    /// Returns a [`LeaderboardRanking`](crate::types::LeaderboardRanking).
+    ///
+    /// # Arguments
+    /// * `leaderboard_id` - The leaderboard ID (see [`get_hub_leaderboards`](Self::get_hub_leaderboards))
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let standings = client.get_leaderboard("leaderboard-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_leaderboard(
+        &self,
+        leaderboard_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<LeaderboardRanking, Error> {
+        let url = format!("{}/leaderboards/{}", self.api_url, leaderboard_id);
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", &offset.to_string())]);
+        }
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", &limit.to_string())]);
+        }
+
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // Generic Requests
+    // ============================================================================
+
+    /// Send a GET request to any Data API endpoint and deserialize the response into `T`
+    ///
+    /// Goes through the same pipeline as the typed methods (authentication,
+    /// error mapping, metrics, ETag caching and coalescing when enabled), so it
+    /// can reach endpoints this crate does not wrap, or decode a known endpoint
+    /// into a narrower struct.
+    ///
+    /// Serde ignores fields that `T` does not declare, so a struct holding only
+    /// the fields you need skips building the rest of a large response. Use
+    /// [`serde_json::Value`] for `T` to get the raw JSON.
+    ///
+    /// # Arguments
+    /// * `path` - Path relative to the API root, e.g. `"/players/{id}"`
+    /// * `query` - Query parameters to append
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the HTTP request fails.
+    /// Returns [`Error::Api`] if the API returns an error response.
+    /// Returns [`Error::Json`] if the response cannot be parsed into `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Nickname {
+    ///     nickname: String,
+    /// }
+    ///
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player: Nickname = client.get_as("/players/player-id", &[]).await?;
+    /// println!("{}", player.nickname);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_as<T>(&self, path: &str, query: &[(&str, &str)]) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = format!("{}/{}", self.api_url, path.trim_start_matches('/'));
+        let mut request = self.reqwest_client.get(&url);
+
+        if !query.is_empty() {
+            request = request.query(query);
+        }
+
+        self.send(request).await
+    }
+
+    // ============================================================================
+    // Helper Methods
+    // ============================================================================
+
+    fn add_headers(&self, mut request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if let Some(ref api_key) = self.api_key {
+            request = request.header("Authorization", format!("Bearer {}", api_key.as_str()));
+        }
+        if let Some(ref language) = self.language {
+            request = request.header(reqwest::header::ACCEPT_LANGUAGE, language.clone());
+        }
+        request
+    }
+
+    /// Authenticate, send and decode a request
+    ///
+    /// Errors are wrapped in [`Error::Request`] naming the request.
+    async fn send<T>(&self, request: reqwest::RequestBuilder) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let request = self.add_headers(request).build()?;
+        let method = request.method().to_string();
+        let url = request.url().to_string();
+
+        self.send_request(request)
+            .await
+            .map_err(|source| Error::Request {
+                method,
+                url,
+                source: Box::new(source),
+            })
+    }
+
+    async fn send_request<T>(&self, mut request: reqwest::Request) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.acquire()?;
+        }
+
+        let url = cache_key(request.url());
+        if let Some(etag) = self.stored_etag(&url)
+            && let Ok(value) = reqwest::header::HeaderValue::from_str(&etag)
+        {
+            request
+                .headers_mut()
+                .insert(reqwest::header::IF_NONE_MATCH, value);
+        }
+
+        #[cfg(feature = "coalesce")]
+        let response = match &self.in_flight {
+            Some(in_flight) => in_flight.run(&url, self.execute(request)).await?,
+            None => self.execute(request).await?,
+        };
+        #[cfg(not(feature = "coalesce"))]
+        let response = self.execute(request).await?;
+
+        self.handle_response(&url, response)
+    }
+
+    /// Send a request and read its body, reporting it to the metrics hooks and
+    /// the circuit breaker
+    ///
+    /// The returned future owns everything it needs, so it can be shared
+    /// between coalesced callers. It is not declared `Send`, as reqwest's
+    /// futures are not on wasm32; on native targets it is, which coalescing
+    /// relies on.
+    fn execute(
+        &self,
+        request: reqwest::Request,
+    ) -> impl Future<Output = Result<RawResponse, Error>> + 'static {
+        let client = self.reqwest_client.clone();
+        let metrics = self.metrics.clone();
+        let breaker = self.circuit_breaker.clone();
+        let concurrency = self.concurrency.clone();
+
+        async move {
+            let _permit = match concurrency {
+                Some(limit) => Some(limit.acquire().await),
+                None => None,
+            };
+            let path = request.url().path().to_string();
+            if let Some(metrics) = &metrics {
+                metrics.on_request_start(&path);
+            }
+            let started = Instant::now();
+
+            let (status, result) = match client.execute(request).await {
+                Ok(response) => (
+                    Some(response.status().as_u16()),
+                    RawResponse::read(response).await,
+                ),
+                Err(e) => (None, Err(e.into())),
+            };
+
+            if let Some(metrics) = &metrics {
+                metrics.on_request_end(&path, status, started.elapsed());
+            }
+            if let Some(breaker) = &breaker {
+                breaker.record(status);
+            }
+            result
+        }
+    }
+
+    /// Look up the stored `ETag` for a URL when conditional requests are enabled
+    fn stored_etag(&self, url: &str) -> Option<String> {
+        let etags = self
+            .etags
+            .as_ref()?
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        etags.get(url).map(|(etag, _)| etag.clone())
+    }
+
+    /// Decode a response, applying conditional request handling if enabled
+    ///
+    /// With conditional requests, the body of successful responses carrying an
+    /// `ETag` is stored, and `304 Not Modified` is answered from the stored body.
+    fn handle_response<T>(&self, url: &str, response: RawResponse) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let RawResponse {
+            status,
+            content_type,
+            etag,
+            body,
+        } = response;
+
+        if let Some(etags) = &self.etags {
+            let mut etags = etags.lock().unwrap_or_else(|e| e.into_inner());
+            if status == reqwest::StatusCode::NOT_MODIFIED {
+                if let Some((_, stored)) = etags.get(url) {
+                    let stored = stored.clone();
+                    drop(etags);
+                    return self.handle_body(reqwest::StatusCode::OK, None, stored);
+                }
+            } else if status.is_success() {
+                match etag {
+                    Some(etag) if content_type.as_deref().is_none_or(is_json_content_type) => {
+                        etags.insert(url, etag, body.clone());
+                    }
+                    _ => {
+                        etags.remove(url);
+                    }
+                }
+            }
+        }
+
+        self.handle_body(status, content_type.as_deref(), body)
+    }
+
+    /// Turn a response status and body into the decoded value or an error
+    ///
+    /// Successful and server error (5xx) responses that are not JSON, such as
+    /// the HTML error pages served during outages, become
+    /// [`Error::UnexpectedContentType`] instead of a JSON parse error.
+    fn handle_body<T>(
+        &self,
+        status: reqwest::StatusCode,
+        content_type: Option<&str>,
+        response_text: String,
+    ) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if let Some(content_type) = content_type
+            && !is_json_content_type(content_type)
+            && (status.is_success() || status.is_server_error())
+        {
+            return Err(Error::UnexpectedContentType {
+                status: status.as_u16(),
+                content_type: content_type.to_string(),
+                snippet: body_snippet(&response_text),
+            });
+        }
+
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            return match status_code {
+                400 => Err(Error::Api(
+                    status_code,
+                    format!("Bad request: {}", response_text),
+                )),
+                401 => Err(Error::InvalidApiKey),
+                403 if is_geo_block(&response_text) => {
+                    Err(Error::GeoBlocked(status_code, response_text))
+                }
+                451 => Err(Error::GeoBlocked(status_code, response_text)),
+                403 => Err(Error::Api(
+                    status_code,
+                    format!("Forbidden: {}", response_text),
+                )),
+                404 => Err(Error::Api(
+                    status_code,
+                    format!("Not found: {}", response_text),
+                )),
+                429 => Err(Error::Api(
+                    status_code,
+                    format!("Too many requests: {}", response_text),
+                )),
+                500 => Err(Error::ServerError),
+                503 => Err(Error::Api(
+                    status_code,
+                    format!("Service temporarily unavailable: {}", response_text),
+                )),
+                _ => Err(Error::Api(status_code, response_text)),
+            };
+        }
+
+        // Try to parse JSON, but provide better error message if it fails
+        match parse_response::<T>(&response_text) {
+            Err(Error::Json(e)) => {
+                // If JSON parsing fails, create a more descriptive error
+                // We'll wrap it in an Api error with the response text
+                Err(Error::Api(
+                    status.as_u16(),
+                    format!(
+                        "Failed to parse JSON response: {}. Response body: {}",
+                        e, response_text
+                    ),
+                ))
+            }
+            result => result,
+        }
+    }
+
+    /// Get the base URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Get the API key if set
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+
+    /// Check that an API key or access token is configured
+    ///
+    /// Requests sent without one are rejected by FACEIT with `401
+    /// Unauthorized`. Call this once at startup, or before a batch of
+    /// requests, to fail fast without spending a request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidApiKey`] if no key is set or the key is blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use faceit::{HttpClient, error::Error};
+    ///
+    /// let client = HttpClient::new();
+    /// assert!(matches!(client.require_auth(), Err(Error::InvalidApiKey)));
+    /// ```
+    pub fn require_auth(&self) -> Result<(), Error> {
+        match self.api_key() {
+            Some(key) if !key.trim().is_empty() => Ok(()),
+            _ => Err(Error::InvalidApiKey),
+        }
+    }
+
+    /// Get the state of the circuit breaker
+    ///
+    /// Always [`CircuitState::Closed`] if no breaker was configured with
+    /// [`ClientBuilder::circuit_breaker`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use faceit::{HttpClient, http::CircuitState};
+    ///
+    /// let client = HttpClient::new();
+    /// let healthy = client.circuit_state() == CircuitState::Closed;
+    /// ```
+    pub fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker
+            .as_ref()
+            .map_or(CircuitState::Closed, |breaker| breaker.state())
+    }
+
+    /// Measure the round-trip time of a lightweight request
+    ///
+    /// Times a request for a single game from the games catalog, including
+    /// connection setup if no pooled connection is available. The request goes
+    /// through the client like any other, so it is reported to the metrics
+    /// hooks and the circuit breaker.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the request if it fails, so an unreachable or
+    /// failing API is not mistaken for a fast one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let latency = client.measure_latency().await?;
+    /// println!("FACEIT responded in {:?}", latency);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn measure_latency(&self) -> Result<Duration, Error> {
+        let started = Instant::now();
+        self.get_all_games(None, Some(1)).await?;
+        Ok(started.elapsed())
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shows the client's configuration with the API key redacted, so a client
+/// embedded in a logged config struct does not leak it
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .field("api_url", &self.api_url)
+            .field("api_key", &self.api_key.as_ref().map(|_| "***"))
+            .field("language", &self.language)
+            .field("circuit_breaker", &self.circuit_breaker.is_some())
+            .field(
+                "max_concurrency",
+                &self.concurrency.as_ref().map(|limit| limit.max()),
+            )
+            .field("conditional_requests", &self.etags.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Status, relevant headers and body of a response, before decoding
+#[derive(Debug, Clone)]
+pub(crate) struct RawResponse {
+    status: reqwest::StatusCode,
+    content_type: Option<String>,
+    etag: Option<String>,
+    body: String,
+}
+
+impl RawResponse {
+    async fn read(response: reqwest::Response) -> Result<Self, Error> {
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value: &reqwest::header::HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let status = response.status();
+        let content_type = header(reqwest::header::CONTENT_TYPE);
+        let etag = header(reqwest::header::ETAG);
+        let body = response.text().await?;
+
+        Ok(Self {
+            status,
+            content_type,
+            etag,
+            body,
+        })
+    }
+}
+
+/// Walk back from a player's current ELO through their matches, newest first
+///
+/// See [`Client::get_elo_history`]. Returns the estimated ELO after each
+/// matchmaking match with a known outcome, oldest first.
+fn estimate_elo_walk(
+    player_id: &str,
+    current_elo: i64,
+    matches: &[MatchHistory],
+) -> Vec<(chrono::DateTime<chrono::Utc>, i64)> {
+    let mut elo = current_elo;
+    let mut points = Vec::new();
+    for history in matches {
+        let is_matchmaking = history.competition_type_kind() == Some(CompetitionType::Matchmaking);
+        let finished_at = history
+            .finished_at
+            .and_then(|at| chrono::DateTime::from_timestamp(at, 0));
+        let (true, Some(finished_at), Some(won)) =
+            (is_matchmaking, finished_at, history.player_won(player_id))
+        else {
+            continue;
+        };
+
+        points.push((finished_at, elo));
+        elo -= if won {
+            NOMINAL_ELO_CHANGE
+        } else {
+            -NOMINAL_ELO_CHANGE
+        };
+    }
+
+    points.reverse();
+    points
+}
+
+/// Collect every item of a paginated endpoint
+///
+/// See [`for_each_page`].
+async fn paginate<T, P, F, Fut>(
+    limit: i64,
+    max_offset: Option<i64>,
+    fetch: F,
+) -> Result<Vec<T>, Error>
+where
+    F: FnMut(i64, i64) -> Fut,
+    Fut: Future<Output = Result<P, Error>>,
+    P: IntoIterator<Item = T>,
+{
+    let mut items = Vec::new();
+    for_each_page(limit, max_offset, fetch, |page| {
+        items.extend(page);
+        ControlFlow::Continue(())
+    })
+    .await?;
+    Ok(items)
+}
+
+/// Page through a paginated endpoint
+///
+/// Requests pages of `limit` items with `fetch(offset, limit)` and hands each
+/// to `visit`, until a page comes back short, the next offset would exceed
+/// `max_offset`, or `visit` breaks.
+async fn for_each_page<T, P, F, Fut>(
+    limit: i64,
+    max_offset: Option<i64>,
+    mut fetch: F,
+    mut visit: impl FnMut(Vec<T>) -> ControlFlow<()>,
+) -> Result<(), Error>
+where
+    F: FnMut(i64, i64) -> Fut,
+    Fut: Future<Output = Result<P, Error>>,
+    P: IntoIterator<Item = T>,
+{
+    let mut offset = 0;
+    loop {
+        let page: Vec<T> = fetch(offset, limit).await?.into_iter().collect();
+        let page_len = page.len() as i64;
+        if visit(page).is_break() || page_len < limit {
+            return Ok(());
+        }
+        offset += page_len;
+        if max_offset.is_some_and(|max| offset > max) {
+            return Ok(());
+        }
+    }
+}
+
+/// Key identifying a request in the ETag store and among coalesced requests
+///
+/// This is the full URL including the query string, with query parameters
+/// sorted so that the same parameters added in a different order share a key,
+/// while requests differing in any parameter (e.g. `offset` or `expanded`)
+/// never collide.
+fn cache_key(url: &reqwest::Url) -> String {
+    let mut pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    if pairs.is_empty() {
+        return url.to_string();
+    }
+    pairs.sort();
+
+    let mut key = url.clone();
+    key.query_pairs_mut().clear().extend_pairs(pairs);
+    key.to_string()
+}
+
+/// Whether a `Content-Type` value denotes JSON (`application/json`, `*+json`)
+fn is_json_content_type(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+/// Whether a `403 Forbidden` body says the resource is blocked in the caller's region
+fn is_geo_block(body: &str) -> bool {
+    const MARKERS: [&str; 8] = [
+        "geo-block",
+        "geoblock",
+        "geo block",
+        "geo_block",
+        "not available in your country",
+        "not available in your region",
+        "restricted in your country",
+        "restricted in your region",
+    ];
+
+    let body = body.to_ascii_lowercase();
+    MARKERS.iter().any(|marker| body.contains(marker))
+}
+
+/// Shorten a response body for error messages, collapsing whitespace
+fn body_snippet(body: &str) -> String {
+    const MAX_CHARS: usize = 200;
+
+    let collapsed = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    match collapsed.char_indices().nth(MAX_CHARS) {
+        Some((end, _)) => format!("{}...", &collapsed[..end]),
+        None => collapsed,
+    }
+}
+
+/// Reject country filters the API would silently ignore (e.g. "UK" instead of "GB")
+fn validate_country(country: &str) -> Result<(), Error> {
+    if is_valid_country_code(country) {
+        Ok(())
+    } else {
+        Err(Error::InvalidCountry(country.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve `responses` (status, JSON body) to successive connections and
+    /// return the base URL to point a client at, plus the received request lines
+    ///
+    /// Bodies starting with `<` are served as `text/html`.
+    async fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let (base_url, requests, _) = serve(responses).await;
+        (base_url, requests)
+    }
+
+    /// Like [`mock_server`], but return the full received request heads
+    /// (request line and headers)
+    async fn mock_server_with_heads(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let (base_url, _, heads) = serve(responses).await;
+        (base_url, heads)
+    }
+
+    async fn serve(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let heads = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+        let received_heads = heads.clone();
+
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = Vec::new();
+                while !buf.ends_with(b"\r\n\r\n") {
+                    let mut chunk = [0u8; 1024];
+                    let n = socket.read(&mut chunk).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                }
+                let request = String::from_utf8_lossy(&buf);
+                let request_line = request.lines().next().unwrap_or_default().to_string();
+                received.lock().unwrap().push(request_line);
+                received_heads.lock().unwrap().push(request.into_owned());
+
+                let content_type = if body.starts_with('<') {
+                    "text/html"
+                } else {
+                    "application/json"
+                };
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: {}\r\nETag: \"mock\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    content_type,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base_url, requests, heads)
+    }
+
+    /// Accept one connection and never respond
+    ///
+    /// The returned task resolves to `true` once the client closes the
+    /// connection.
+    async fn hanging_server() -> (String, tokio::task::JoinHandle<bool>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let closed = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut chunk = [0u8; 1024];
+            loop {
+                match socket.read(&mut chunk).await {
+                    Ok(0) | Err(_) => return true,
+                    Ok(_) => {}
+                }
+            }
+        });
+
+        (base_url, closed)
+    }
+
+    async fn assert_drop_cancels(client: Client, closed: tokio::task::JoinHandle<bool>) {
+        let request = client.get_game("cs2");
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), request)
+                .await
+                .is_err()
+        );
+
+        let closed = tokio::time::timeout(Duration::from_secs(5), closed).await;
+        assert!(matches!(closed, Ok(Ok(true))));
+    }
+
+    #[test]
+    fn test_client_builder() {
+        let builder = ClientBuilder::new();
+        // Test that builder can be created and configured
+        let client = builder
+            .base_url("https://test.example.com")
+            .api_key("test-key")
+            .timeout(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url(), "https://test.example.com");
+        assert_eq!(client.api_key(), Some("test-key"));
+    }
+
+    #[test]
+    fn test_client_builder_with_options() {
+        let client = ClientBuilder::new()
+            .base_url("https://test.example.com")
+            .api_key("test-key")
+            .timeout(Duration::from_secs(60))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url(), "https://test.example.com");
+        assert_eq!(client.api_key(), Some("test-key"));
+    }
+
+    #[test]
+    fn test_require_auth() {
+        assert!(matches!(
+            Client::new().require_auth(),
+            Err(Error::InvalidApiKey)
+        ));
+        let blank = ClientBuilder::new().api_key("  ").build().unwrap();
+        assert!(matches!(blank.require_auth(), Err(Error::InvalidApiKey)));
+        let client = ClientBuilder::new().api_key("test-key").build().unwrap();
+        assert!(client.require_auth().is_ok());
+    }
+
+    #[test]
+    fn test_language_header() {
+        let client = ClientBuilder::new()
+            .api_key("test-key")
+            .language("de")
+            .build()
+            .unwrap();
+        let request = client
+            .add_headers(client.reqwest_client.get("https://test.example.com"))
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()["accept-language"], "de");
+        assert_eq!(request.headers()["authorization"], "Bearer test-key");
+
+        assert!(matches!(
+            ClientBuilder::new().language("de\n").build(),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_api_key() {
+        let client = ClientBuilder::new()
+            .base_url("https://test.example.com")
+            .api_key("secret-key")
+            .build()
+            .unwrap();
+        let debug = format!("{:?}", client);
+        assert!(!debug.contains("secret-key"));
+        assert!(debug.contains(r#"api_key: Some("***")"#));
+        assert!(debug.contains(r#"base_url: "https://test.example.com""#));
+
+        assert!(format!("{:?}", Client::new()).contains("api_key: None"));
+    }
+
+    #[test]
+    fn test_client_default_base_url() {
+        let client = Client::new();
+        assert_eq!(client.base_url(), "https://open.faceit.com");
+    }
+
+    #[test]
+    fn test_client_builder_from_vars() {
+        let vars = |name: &str| match name {
+            "FACEIT_API_KEY" => Some("env-key".to_string()),
+            "FACEIT_BASE_URL" => Some("https://env.example.com".to_string()),
+            "FACEIT_TIMEOUT_SECS" => Some("15".to_string()),
+            _ => None,
+        };
+        let client = ClientBuilder::from_vars(vars).unwrap().build().unwrap();
+        assert_eq!(client.base_url(), "https://env.example.com");
+        assert_eq!(client.api_key(), Some("env-key"));
+
+        assert!(matches!(
+            ClientBuilder::from_vars(|_| None),
+            Err(Error::MissingParameter(_))
+        ));
+        assert!(matches!(
+            ClientBuilder::from_vars(|name| match name {
+                "FACEIT_API_KEY" => Some("env-key".to_string()),
+                "FACEIT_TIMEOUT_SECS" => Some("soon".to_string()),
+                _ => None,
+            }),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_api_version() {
+        let game = r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2"}"#;
+        let (base_url, requests) = mock_server(vec![(200, game), (200, game)]).await;
+
+        let client = ClientBuilder::new().base_url(&base_url).build().unwrap();
+        client.get_game("cs2").await.unwrap();
+        let client = ClientBuilder::new()
+            .base_url(format!("{}/", base_url))
+            .api_version("/v5/")
+            .build()
+            .unwrap();
+        client.get_game("cs2").await.unwrap();
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET /data/v4/games/cs2 HTTP/1.1",
+                "GET /data/v5/games/cs2 HTTP/1.1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_params_match_positional_arguments() {
+        const EMPTY: &str = r#"{"start": 0, "end": 0, "items": []}"#;
+        let (base_url, requests) = mock_server(vec![(200, EMPTY); 4]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        client
+            .get_global_ranking("cs2", "EU", Some("DE"), None, Some(20))
+            .await
+            .unwrap();
+        client
+            .get_global_ranking_with(
+                &GlobalRankingParams::new("cs2", "EU")
+                    .country("DE")
+                    .limit(20),
+            )
+            .await
+            .unwrap();
+        client
+            .search_hubs("weekly", Some("cs2"), None, Some(10), None)
+            .await
+            .unwrap();
+        client
+            .search_hubs_with(&SearchHubsParams::new("weekly").game("cs2").offset(10))
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0], requests[1]);
+        assert_eq!(requests[2], requests[3]);
+    }
+
+    #[tokio::test]
+    async fn test_empty_required_params_rejected_before_request() {
+        let client = ClientBuilder::new()
+            .base_url("http://127.0.0.1:9")
+            .build()
+            .unwrap();
+
+        let params = GlobalRankingParams {
+            game_id: "cs2".into(),
+            ..Default::default()
+        };
+        let result = client.get_global_ranking_with(&params).await;
+        assert!(matches!(result, Err(Error::MissingParameter(ref name)) if name == "region"));
+
+        let result = client
+            .search_players_with(&SearchPlayersParams::default())
+            .await;
+        assert!(matches!(result, Err(Error::MissingParameter(ref name)) if name == "nickname"));
+    }
+
+    #[tokio::test]
+    async fn test_match_type_string_rejected_before_request() {
+        let (base_url, requests) =
+            mock_server(vec![(200, r#"{"start": 0, "end": 0, "items": []}"#)]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let result = client
+            .get_hub_matches_str("hub-id", Some("live"), None, None)
+            .await;
+        assert!(matches!(result, Err(Error::InvalidParameter(ref msg)) if msg.contains("live")));
+        assert!(requests.lock().unwrap().is_empty());
+
+        client
+            .get_hub_matches_str("hub-id", Some("PAST"), None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/hubs/hub-id/matches?type=past HTTP/1.1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_country_rejected_before_request() {
+        // Unroutable base URL: the call must fail on validation, not on the network
+        let client = ClientBuilder::new()
+            .base_url("http://127.0.0.1:0")
+            .build()
+            .unwrap();
+        let result = client
+            .search_players("s1mple", None, Some("UK"), None, None)
+            .await;
+        assert!(matches!(result, Err(Error::InvalidCountry(ref c)) if c == "UK"));
+        assert!(validate_country("gb").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_maps_to_timeout_error() {
+        // Accepts connections (via the backlog) but never responds
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = ClientBuilder::new()
+            .base_url(format!("http://{}", listener.local_addr().unwrap()))
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let error = client.get_player("player-id").await.unwrap_err();
+        assert!(matches!(error.inner(), Error::Timeout));
+        assert!(error.url().unwrap().ends_with("/data/v4/players/player-id"));
+        assert!(error.to_string().contains("(GET http://"));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_wait_for_finish_deadline_is_not_a_request_timeout() {
+        // Accepts connections (via the backlog) but never responds
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = ClientBuilder::new()
+            .base_url(format!("http://{}", listener.local_addr().unwrap()))
+            .build()
+            .unwrap();
+
+        let error = crate::http::ergonomic::Match::new("m-1", &client)
+            .wait_for_finish(Duration::from_millis(10), Duration::from_millis(100))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::WaitTimeout(timeout) if timeout == Duration::from_millis(100))
+        );
+        assert!(error.url().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refused_connection_maps_to_connection_error() {
+        // Grab a free port, then close it so the connection is refused
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = ClientBuilder::new()
+            .base_url(format!("http://{}", addr))
+            .build()
+            .unwrap();
+
+        let result = client
+            .get_player("player-id")
+            .await
+            .map_err(Error::into_inner);
+        let Err(Error::Connection(source)) = result else {
+            panic!("expected a connection error, got {:?}", result);
+        };
+        assert!(source.to_string().contains("/data/v4/players/player-id"));
+        assert!(source.downcast_ref::<reqwest::Error>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_measure_latency() {
+        let (base_url, requests) = mock_server(vec![
+            (200, r#"{"start": 0, "end": 0, "items": []}"#),
+            (503, r#"{"errors": []}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        assert!(client.measure_latency().await.unwrap() > Duration::ZERO);
+        assert_eq!(
+            client.measure_latency().await.unwrap_err().status(),
+            Some(503)
         );
-        let mut request = self.reqwest_client.get(&url);
+        assert_eq!(
+            requests.lock().unwrap()[0],
+            "GET /data/v4/games?limit=1 HTTP/1.1"
+        );
+    }
 
-        if let Some(country) = country {
-            request = request.query(&[("country", country)]);
-        }
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", &limit.to_string())]);
-        }
+    #[tokio::test]
+    async fn test_suggest_players() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            r#"{"start": 0, "end": 2, "items": [
+                {"player_id": "p-1", "nickname": "s1mple", "avatar": "https://example.com/a.png", "games": []},
+                {"player_id": "p-2", "nickname": "s1mpleton"}
+            ]}"#,
+        )])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
 
-        let request = self.add_api_key_header(request);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        assert!(
+            client
+                .suggest_players("  ", None, 5)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let suggestions = client
+            .suggest_players(" s1m ", Some("cs2"), 50)
+            .await
+            .unwrap();
+        assert_eq!(
+            suggestions,
+            vec![
+                PlayerSuggestion {
+                    player_id: "p-1".to_string(),
+                    nickname: "s1mple".to_string(),
+                    avatar: Some("https://example.com/a.png".to_string()),
+                },
+                PlayerSuggestion {
+                    player_id: "p-2".to_string(),
+                    nickname: "s1mpleton".to_string(),
+                    avatar: None,
+                },
+            ]
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec!["GET /data/v4/search/players?nickname=s1m&game=cs2&limit=20 HTTP/1.1"]
+        );
     }
 
-    // ============================================================================
-    // Helper Methods
-    // ============================================================================
+    #[tokio::test]
+    async fn test_metrics_hooks() {
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(String, Option<u16>)>>);
 
-    fn add_api_key_header(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        if let Some(ref api_key) = self.api_key {
-            request.header("Authorization", format!("Bearer {}", api_key.as_str()))
-        } else {
-            request
+        impl Metrics for Recorder {
+            fn on_request_end(&self, path: &str, status: Option<u16>, _elapsed: Duration) {
+                self.0.lock().unwrap().push((path.to_string(), status));
+            }
         }
+
+        let (base_url, _) = mock_server(vec![
+            (
+                200,
+                r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2"}"#,
+            ),
+            (404, r#"{"errors": []}"#),
+        ])
+        .await;
+        let recorder = Arc::new(Recorder::default());
+        let client = ClientBuilder::new()
+            .base_url(base_url)
+            .metrics(recorder.clone())
+            .build()
+            .unwrap();
+
+        client.get_game("cs2").await.unwrap();
+        assert!(client.get_game("unknown").await.is_err());
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                ("/data/v4/games/cs2".to_string(), Some(200)),
+                ("/data/v4/games/unknown".to_string(), Some(404)),
+            ]
+        );
     }
 
-    async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T, Error>
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        let status = response.status();
-        let response_text = response.text().await?;
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        let (base_url, requests) = mock_server(vec![
+            (503, r#"{"errors": []}"#),
+            (500, r#"{"errors": []}"#),
+            (
+                200,
+                r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2"}"#,
+            ),
+        ])
+        .await;
+        let client = ClientBuilder::new()
+            .base_url(base_url)
+            .circuit_breaker(CircuitBreaker::new(2).cooldown(Duration::from_millis(200)))
+            .build()
+            .unwrap();
 
-        if !status.is_success() {
-            let status_code = status.as_u16();
-            return match status_code {
-                400 => Err(Error::Api(
-                    status_code,
-                    format!("Bad request: {}", response_text),
-                )),
-                401 => Err(Error::InvalidApiKey),
-                403 => Err(Error::Api(
-                    status_code,
-                    format!("Forbidden: {}", response_text),
-                )),
-                404 => Err(Error::Api(
-                    status_code,
-                    format!("Not found: {}", response_text),
-                )),
-                429 => Err(Error::Api(
-                    status_code,
-                    format!("Too many requests: {}", response_text),
-                )),
-                500 => Err(Error::ServerError),
-                503 => Err(Error::Api(
-                    status_code,
-                    format!("Service temporarily unavailable: {}", response_text),
-                )),
-                _ => Err(Error::Api(status_code, response_text)),
-            };
-        }
+        assert!(client.get_game("cs2").await.is_err());
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
+        assert!(client.get_game("cs2").await.is_err());
+        assert!(matches!(client.circuit_state(), CircuitState::Open { .. }));
 
-        // Try to parse JSON, but provide better error message if it fails
-        match serde_json::from_str::<T>(&response_text) {
-            Ok(json) => Ok(json),
-            Err(e) => {
-                // If JSON parsing fails, create a more descriptive error
-                // We'll wrap it in an Api error with the response text
-                Err(Error::Api(
-                    status.as_u16(),
-                    format!(
-                        "Failed to parse JSON response: {}. Response body: {}",
-                        e, response_text
-                    ),
-                ))
-            }
-        }
+        // Short-circuited without reaching the server
+        assert!(matches!(
+            client.get_game("cs2").await.map_err(Error::into_inner),
+            Err(Error::CircuitOpen)
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(client.circuit_state(), CircuitState::HalfOpen);
+        client.get_game("cs2").await.unwrap();
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
     }
 
-    /// Get the base URL
-    pub fn base_url(&self) -> &str {
-        &self.base_url
+    #[test]
+    fn test_circuit_breaker_ignores_stale_failures() {
+        let breaker = Breaker::new(CircuitBreaker::new(1).cooldown(Duration::from_millis(50)));
+        breaker.acquire().unwrap();
+        breaker.record(None);
+        assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+
+        // A request sent before the circuit opened fails after it did
+        std::thread::sleep(Duration::from_millis(60));
+        breaker.record(Some(503));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        // The probe fails: open for another cooldown
+        breaker.acquire().unwrap();
+        breaker.record(Some(503));
+        assert!(matches!(breaker.state(), CircuitState::Open { .. }));
     }
 
-    /// Get the API key if set
-    pub fn api_key(&self) -> Option<&str> {
-        self.api_key.as_deref()
+    #[tokio::test]
+    async fn test_conditional_requests() {
+        let (base_url, heads) = mock_server_with_heads(vec![
+            (
+                200,
+                r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2"}"#,
+            ),
+            (304, ""),
+        ])
+        .await;
+        let client = ClientBuilder::new()
+            .base_url(base_url)
+            .conditional_requests(true)
+            .build()
+            .unwrap();
+
+        let first = client.get_game("cs2").await.unwrap();
+        let second = client.get_game("cs2").await.unwrap();
+        assert_eq!(first.long_label, second.long_label);
+
+        let heads = heads.lock().unwrap();
+        assert_eq!(heads.len(), 2);
+        let revalidates = |head: &str| {
+            head.lines()
+                .any(|line| line.eq_ignore_ascii_case("if-none-match: \"mock\""))
+        };
+        assert!(!revalidates(&heads[0]));
+        assert!(revalidates(&heads[1]));
     }
-}
 
-impl Default for Client {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn test_etag_store_is_bounded() {
+        let mut store = EtagStore::new(2);
+        store.insert("a", "1".into(), "body-a".into());
+        store.insert("b", "2".into(), "body-b".into());
+        // Re-storing a URL makes it the newest
+        store.insert("a", "3".into(), "body-a".into());
+        store.insert("c", "4".into(), "body-c".into());
+
+        assert_eq!(store.len(), 2);
+        assert!(store.get("b").is_none());
+        assert_eq!(store.get("a").map(|(etag, _)| etag.as_str()), Some("3"));
+        assert!(store.get("c").is_some());
+
+        store.remove("a");
+        assert_eq!(store.len(), 1);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn test_unexpected_content_type() {
+        let (base_url, _) = mock_server(vec![
+            (200, "<html><body>\n  <h1>Maintenance</h1>\n</body></html>"),
+            (502, "<html><body>Bad gateway</body></html>"),
+            (500, "<html><body>Internal error</body></html>"),
+            (500, r#"{"errors": []}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        match client.get_game("cs2").await.map_err(Error::into_inner) {
+            Err(Error::UnexpectedContentType {
+                status,
+                content_type,
+                snippet,
+            }) => {
+                assert_eq!(status, 200);
+                assert_eq!(content_type, "text/html");
+                assert_eq!(snippet, "<html><body> <h1>Maintenance</h1> </body></html>");
+            }
+            other => panic!("expected UnexpectedContentType, got {:?}", other),
+        }
+        assert!(matches!(
+            client.get_game("cs2").await.map_err(Error::into_inner),
+            Err(Error::UnexpectedContentType { status: 502, .. })
+        ));
+        assert!(matches!(
+            client.get_game("cs2").await.map_err(Error::into_inner),
+            Err(Error::UnexpectedContentType { status: 500, .. })
+        ));
+        assert!(matches!(
+            client.get_game("cs2").await.map_err(Error::into_inner),
+            Err(Error::ServerError)
+        ));
+    }
 
     #[test]
-    fn test_client_builder() {
-        let builder = ClientBuilder::new();
-        // Test that builder can be created and configured
-        let client = builder
+    fn test_cache_key() {
+        let client = ClientBuilder::new()
             .base_url("https://test.example.com")
-            .api_key("test-key")
-            .timeout(Duration::from_secs(60))
             .build()
             .unwrap();
-        assert_eq!(client.base_url(), "https://test.example.com");
-        assert_eq!(client.api_key(), Some("test-key"));
+        let key = |request: reqwest::RequestBuilder| cache_key(request.build().unwrap().url());
+        let url = "https://test.example.com/data/v4/hubs/hub-1";
+
+        let plain = key(client.reqwest_client.get(url));
+        let expanded = key(client
+            .reqwest_client
+            .get(url)
+            .query(&[("expanded", "organizer")]));
+        let expanded_game = key(client
+            .reqwest_client
+            .get(url)
+            .query(&[("expanded", "game")]));
+        assert_ne!(plain, expanded);
+        assert_ne!(expanded, expanded_game);
+
+        let ordered = key(client
+            .reqwest_client
+            .get(url)
+            .query(&[("offset", "0"), ("limit", "20")]));
+        let reordered = key(client
+            .reqwest_client
+            .get(url)
+            .query(&[("limit", "20"), ("offset", "0")]));
+        assert_eq!(ordered, reordered);
+        assert_ne!(
+            ordered,
+            key(client
+                .reqwest_client
+                .get(url)
+                .query(&[("offset", "20"), ("limit", "20")]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conditional_requests_distinguish_expansions() {
+        let (base_url, _) = mock_server(vec![
+            (
+                200,
+                r#"{"hub_id": "hub-1", "name": "Hub", "game_id": "cs2", "organizer_id": "o-1"}"#,
+            ),
+            (304, ""),
+        ])
+        .await;
+        let client = ClientBuilder::new()
+            .base_url(base_url)
+            .conditional_requests(true)
+            .build()
+            .unwrap();
+
+        client.get_hub("hub-1", Some(&["organizer"])).await.unwrap();
+        // No body is stored for the unexpanded URL, so the 304 cannot be served
+        assert!(client.get_hub("hub-1", None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_geo_blocked() {
+        let (base_url, _) = mock_server(vec![
+            (
+                451,
+                r#"{"errors": [{"message": "Unavailable for legal reasons"}]}"#,
+            ),
+            (
+                403,
+                r#"{"errors": [{"message": "This content is not available in your country"}]}"#,
+            ),
+            (403, r#"{"errors": [{"message": "Forbidden"}]}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let error = client.get_game("cs2").await.unwrap_err();
+        assert!(matches!(error.inner(), Error::GeoBlocked(451, _)));
+        assert_eq!(error.snapshot().status, Some(451));
+        let error = client.get_game("cs2").await.unwrap_err();
+        assert!(matches!(error.inner(), Error::GeoBlocked(403, _)));
+        assert_eq!(error.snapshot().status, Some(403));
+        let error = client.get_game("cs2").await.unwrap_err();
+        assert!(matches!(error.inner(), Error::Api(403, _)));
+        assert!(error.to_string().ends_with("/data/v4/games/cs2)"));
     }
 
     #[test]
-    fn test_client_builder_with_options() {
+    fn test_body_snippet() {
+        assert!(is_json_content_type("application/json; charset=utf-8"));
+        assert!(is_json_content_type("application/problem+json"));
+        assert!(!is_json_content_type("text/html"));
+
+        let snippet = body_snippet(&"é".repeat(300));
+        assert_eq!(snippet.chars().count(), 203);
+        assert!(snippet.ends_with("..."));
+    }
+
+    #[cfg(feature = "coalesce")]
+    #[tokio::test]
+    async fn test_coalesce_requests() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2"}"#,
+        )])
+        .await;
         let client = ClientBuilder::new()
-            .base_url("https://test.example.com")
-            .api_key("test-key")
-            .timeout(Duration::from_secs(60))
+            .base_url(base_url)
+            .coalesce_requests(true)
             .build()
             .unwrap();
 
-        assert_eq!(client.base_url(), "https://test.example.com");
-        assert_eq!(client.api_key(), Some("test-key"));
+        let (first, second) = tokio::join!(client.get_game("cs2"), client.get_game("cs2"));
+        assert_eq!(first.unwrap().game_id, "cs2");
+        assert_eq!(second.unwrap().game_id, "cs2");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_hub_report() {
+        let (base_url, requests) = mock_server(vec![
+            (
+                200,
+                r#"{"start": 0, "end": 2, "items": [
+                    {"match_id": "m-2", "game": "cs2", "status": "FINISHED", "started_at": 1700000200},
+                    {"match_id": "m-1", "game": "cs2", "status": "CANCELLED", "started_at": 1700000100}
+                ]}"#,
+            ),
+            (
+                200,
+                r#"{"rounds": [{"teams": [{"players": [{"player_id": "p-1", "nickname": "alpha", "player_stats": {"Kills": "20"}}]}]}]}"#,
+            ),
+            (404, r#"{"errors": []}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let report = client
+            .get_hub_report("hub-1", 1_700_000_000, 1_700_001_000, true)
+            .await
+            .unwrap();
+        assert_eq!(report.len(), 2);
+        assert!(report.matches[0].stats.is_some());
+        assert!(report.matches[1].stats.is_none());
+        assert_eq!(report.player_totals()["p-1"].total("Kills"), Some(20.0));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        let (base_url, _) = mock_server(vec![
+            (
+                200,
+                r#"{"start": 0, "end": 1, "items": [
+                    {"match_id": "m-1", "game": "cs2", "status": "FINISHED", "started_at": 1700000100}
+                ]}"#,
+            ),
+            (429, r#"{"errors": []}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        let error = client
+            .get_hub_report("hub-1", 1_700_000_000, 1_700_001_000, true)
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(429));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_full() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            r#"{"tournament_id": "t-1", "name": "Cup", "game_id": "cs2", "organizer_id": "o-1", "status": "finished", "description": "Full details"}"#,
+        )])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let summary: TournamentSimple = parse_response(
+            r#"{"tournament_id": "t-1", "name": "Cup", "game_id": "cs2", "organizer_id": "o-1", "status": "finished"}"#,
+        )
+        .unwrap();
+        let tournament = summary.fetch_full(&client).await.unwrap();
+        assert_eq!(tournament.description.as_deref(), Some("Full details"));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/tournaments/t-1 HTTP/1.1"]
+        );
+
+        let hub: CompetitionSearch = parse_response(
+            r#"{"competition_id": "h-1", "competition_type": "hub", "name": "Hub", "organizer_id": "o-1"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            hub.fetch_championship(&client).await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_player_history_all_dedupes_boundaries() {
+        let history_page = |started_at: std::ops::RangeInclusive<i64>| {
+            let items: Vec<String> = started_at
+                .rev()
+                .map(|at| {
+                    format!(
+                        r#"{{"match_id": "m-{at}", "game_id": "cs2", "status": "FINISHED", "started_at": {at}}}"#
+                    )
+                })
+                .collect();
+            format!(
+                r#"{{"start": 0, "end": 0, "items": [{}]}}"#,
+                items.join(",")
+            )
+            .leak()
+        };
+        // The second page repeats the match that started at the boundary
+        let (base_url, requests) = mock_server(vec![
+            (200, history_page(1901..=2000)),
+            (200, history_page(1850..=1901)),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let matches = client
+            .get_player_history_all("p-1", "cs2", None, Some(2001))
+            .await
+            .unwrap();
+        assert_eq!(matches.len(), 151);
+        let unique: HashSet<&str> = matches.iter().map(|m| m.match_id.as_str()).collect();
+        assert_eq!(unique.len(), 151);
+        assert_eq!(matches.last().unwrap().match_id, "m-1850");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].contains("to=2001"));
+        assert!(requests[1].contains("to=1902"));
+    }
+
+    #[tokio::test]
+    async fn test_dropping_request_cancels_it() {
+        let (base_url, closed) = hanging_server().await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        assert_drop_cancels(client, closed).await;
+    }
+
+    #[cfg(feature = "coalesce")]
+    #[tokio::test]
+    async fn test_dropping_coalesced_request_cancels_it() {
+        let (base_url, closed) = hanging_server().await;
+        let client = ClientBuilder::new()
+            .base_url(base_url)
+            .coalesce_requests(true)
+            .build()
+            .unwrap();
+        assert_drop_cancels(client, closed).await;
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_stats_not_ready() {
+        let (base_url, requests) = mock_server(vec![
+            (404, r#"{"errors": []}"#),
+            (
+                200,
+                r#"{"match_id": "m-1", "game": "cs2", "status": "ONGOING"}"#,
+            ),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let result = crate::http::ergonomic::Match::new("m-1", &client)
+            .stats()
+            .await;
+        assert!(matches!(result, Err(Error::StatsNotReady(status)) if status == "ONGOING"));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET /data/v4/matches/m-1/stats HTTP/1.1",
+                "GET /data/v4/matches/m-1 HTTP/1.1"
+            ]
+        );
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_full_rosters() {
+        const MATCH: &str = r#"{"match_id": "m-1", "game": "cs2", "status": "FINISHED", "teams": {
+            "faction1": {"roster": [{"player_id": "p-1", "nickname": "alpha"}]}
+        }}"#;
+        let (base_url, _) = mock_server(vec![
+            (200, MATCH),
+            (404, r#"{"errors": []}"#),
+            (200, MATCH),
+            (429, r#"{"errors": []}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        let match_obj = crate::http::ergonomic::Match::new("m-1", &client);
+
+        // Deleted profiles are skipped
+        assert!(match_obj.full_rosters().await.unwrap().is_empty());
+        // Anything else fails the call
+        let error = match_obj.full_rosters().await.unwrap_err();
+        assert_eq!(error.status(), Some(429));
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_teams_full() {
+        const SUBSCRIPTIONS: &str =
+            r#"{"start": 0, "end": 1, "items": [{"team": {"team_id": "t-1"}}]}"#;
+        let (base_url, _) = mock_server(vec![
+            (200, SUBSCRIPTIONS),
+            (404, r#"{"errors": []}"#),
+            (200, SUBSCRIPTIONS),
+            (503, r#"{"errors": []}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        let championship = crate::http::ergonomic::Championship::new("c-1", &client);
+
+        // Deleted teams are skipped
+        assert!(championship.teams_full().await.unwrap().is_empty());
+        // Anything else fails the call
+        let error = championship.teams_full().await.unwrap_err();
+        assert_eq!(error.status(), Some(503));
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_championship_bracket() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            r#"{"start": 0, "end": 4, "items": [
+                {"match_id": "m-3", "game": "cs2", "status": "SCHEDULED", "round": 2, "group": 1},
+                {"match_id": "m-1", "game": "cs2", "status": "FINISHED", "round": 1, "group": 1},
+                {"match_id": "m-4", "game": "cs2", "status": "FINISHED", "round": 1, "group": 2},
+                {"match_id": "m-2", "game": "cs2", "status": "FINISHED", "round": 1, "group": 1}
+            ]}"#,
+        )])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let bracket = crate::http::ergonomic::Championship::new("c-1", &client)
+            .bracket()
+            .await
+            .unwrap();
+        let ids: Vec<((i64, i64), Vec<&str>)> = bracket
+            .iter()
+            .map(|(key, matches)| {
+                let ids = matches.iter().map(|m| m.match_id.as_str()).collect();
+                (*key, ids)
+            })
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                ((1, 1), vec!["m-1", "m-2"]),
+                ((1, 2), vec!["m-4"]),
+                ((2, 1), vec!["m-3"]),
+            ]
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/championships/c-1/matches?type=all&offset=0&limit=100 HTTP/1.1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_hub_roles_pages() {
+        let roles: Vec<String> = (0..=MAX_HUB_ROLES_PAGE_LIMIT)
+            .map(|i| format!(r#"{{"role_id": "r-{}", "name": "Role {}"}}"#, i, i))
+            .collect();
+        let (first, second) = roles.split_at(MAX_HUB_ROLES_PAGE_LIMIT as usize);
+        let page = |items: &[String]| -> &'static str {
+            Box::leak(
+                format!(
+                    r#"{{"start": 0, "end": 0, "items": [{}]}}"#,
+                    items.join(",")
+                )
+                .into_boxed_str(),
+            )
+        };
+        let (base_url, requests) = mock_server(vec![(200, page(first)), (200, page(second))]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let roles = client.get_all_hub_roles("h-1").await.unwrap();
+        assert_eq!(roles.len(), 51);
+        assert_eq!(roles[50].role_id, "r-50");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET /data/v4/hubs/h-1/roles?offset=0&limit=50 HTTP/1.1",
+                "GET /data/v4/hubs/h-1/roles?offset=50&limit=50 HTTP/1.1",
+            ]
+        );
     }
 
     #[test]
-    fn test_client_default_base_url() {
-        let client = Client::new();
-        assert_eq!(client.base_url(), "https://open.faceit.com");
+    fn test_estimate_elo_walk() {
+        let history =
+            |match_id: &str, competition_type: &str, finished_at: i64, winner: Option<&str>| {
+                parse_response::<MatchHistory>(&format!(
+                    r#"{{"match_id": "{}", "game_id": "cs2", "status": "FINISHED",
+                    "competition_type": "{}", "finished_at": {}, {}
+                    "teams": {{
+                        "faction1": {{"players": [{{"player_id": "p-1", "nickname": "alpha"}}]}},
+                        "faction2": {{"players": [{{"player_id": "p-2", "nickname": "beta"}}]}}
+                    }}}}"#,
+                    match_id,
+                    competition_type,
+                    finished_at,
+                    winner.map_or(String::new(), |winner| format!(
+                        r#""results": {{"winner": "{}"}},"#,
+                        winner
+                    ))
+                ))
+                .unwrap()
+            };
+        // Newest first, as returned by the history endpoint
+        let matches = [
+            history("m-5", "matchmaking", 500, Some("faction1")),
+            history("m-4", "hub", 400, Some("faction1")),
+            history("m-3", "matchmaking", 300, Some("faction2")),
+            history("m-2", "matchmaking", 200, None),
+            history("m-1", "matchmaking", 100, Some("faction1")),
+        ];
+
+        let at = |secs| chrono::DateTime::from_timestamp(secs, 0).unwrap();
+        // Walking back from 2000: won m-5 (1975 before it), lost m-3 (2000
+        // before it); the hub match and the match without a result are skipped
+        assert_eq!(
+            estimate_elo_walk("p-1", 2000, &matches),
+            [(at(100), 2000), (at(300), 1975), (at(500), 2000)]
+        );
+        assert!(estimate_elo_walk("p-3", 2000, &matches).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_all_helpers_request_max_page_size() {
+        const EMPTY: &str = r#"{"start": 0, "end": 0, "items": []}"#;
+        let (base_url, requests) = mock_server(vec![(200, EMPTY); 7]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        client.get_active_player_bans("p-1").await.unwrap();
+        client
+            .get_player_history_all("p-1", "cs2", None, None)
+            .await
+            .unwrap();
+        client.get_all_games_map().await.unwrap();
+        client.get_all_hub_members("h-1").await.unwrap();
+        client.is_hub_member("h-1", "p-1").await.unwrap();
+        client.get_all_championship_matches("c-1").await.unwrap();
+        client
+            .get_all_championship_subscriptions("c-1")
+            .await
+            .unwrap();
+
+        let limits: Vec<(String, i64)> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|line| {
+                let path = line.split(' ').nth(1).unwrap();
+                let (path, query) = path.split_once('?').unwrap();
+                let limit = query
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix("limit="))
+                    .unwrap();
+                (path.to_string(), limit.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(
+            limits,
+            [
+                ("/data/v4/players/p-1/bans".to_string(), MAX_PAGE_LIMIT),
+                ("/data/v4/players/p-1/history".to_string(), MAX_PAGE_LIMIT),
+                ("/data/v4/games".to_string(), MAX_PAGE_LIMIT),
+                ("/data/v4/hubs/h-1/members".to_string(), MAX_HUB_PAGE_LIMIT),
+                ("/data/v4/players/p-1/hubs".to_string(), MAX_HUB_PAGE_LIMIT),
+                (
+                    "/data/v4/championships/c-1/matches".to_string(),
+                    MAX_PAGE_LIMIT
+                ),
+                (
+                    "/data/v4/championships/c-1/subscriptions".to_string(),
+                    MAX_SUBSCRIPTIONS_PAGE_LIMIT
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_hub_get_expanded() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            r#"{"hub_id": "h-1", "name": "Hub", "game_id": "cs2", "organizer_id": "o-1",
+                "organizer_data": {"organizer_id": "o-1", "name": "Org"}}"#,
+        )])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let hub = crate::http::ergonomic::Hub::new("h-1", &client)
+            .get_expanded()
+            .await
+            .unwrap();
+        assert_eq!(hub.organizer_data.unwrap().name, "Org");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/hubs/h-1?expanded=organizer%2Cgame HTTP/1.1"]
+        );
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_best_global_ranking() {
+        // Regions are queried concurrently, so responses may arrive in any order
+        let (base_url, requests) = mock_server(vec![
+            (
+                200,
+                r#"{"player_id": "p-1", "nickname": "alpha", "country": "gb",
+                    "games": {"cs2": {"region": "EU", "faceit_elo": 2100, "skill_level": 10, "regions": {"EU": {}, "US": {}}}}}"#,
+            ),
+            (
+                200,
+                r#"{"position": 812, "start": 0, "end": 1, "items": [
+                    {"player_id": "p-1", "nickname": "alpha", "position": 812, "faceit_elo": 2100, "game_skill_level": 10}
+                ]}"#,
+            ),
+            (
+                200,
+                r#"{"position": 95, "start": 0, "end": 1, "items": [
+                    {"player_id": "p-0", "nickname": "omega", "position": 94, "faceit_elo": 2300, "game_skill_level": 10}
+                ]}"#,
+            ),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let best = crate::http::ergonomic::Player::new("p-1", &client)
+            .best_global_ranking("cs2")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            best,
+            GlobalRanking {
+                player_id: "p-1".to_string(),
+                nickname: "alpha".to_string(),
+                position: 95,
+                faceit_elo: 2100,
+                game_skill_level: 10,
+                country: Some("gb".to_string()),
+            }
+        );
+
+        let mut paths = requests.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "GET /data/v4/players/p-1 HTTP/1.1",
+                "GET /data/v4/rankings/games/cs2/regions/EU/players/p-1 HTTP/1.1",
+                "GET /data/v4/rankings/games/cs2/regions/US/players/p-1 HTTP/1.1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_as_projection() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Projection {
+            nickname: String,
+            country: Option<String>,
+        }
+
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            r#"{"player_id": "p-1", "nickname": "alpha", "country": "gb",
+                "games": {"cs2": {"faceit_elo": 2100, "regions": {"EU": {}}}},
+                "memberships": ["free"], "new_field": {"nested": [1, 2, 3]}}"#,
+        )])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let player: Projection = client
+            .get_as("/players/p-1", &[("game", "cs2")])
+            .await
+            .unwrap();
+        assert_eq!(
+            player,
+            Projection {
+                nickname: "alpha".to_string(),
+                country: Some("gb".to_string()),
+            }
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/players/p-1?game=cs2 HTTP/1.1"]
+        );
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_hub_leaderboards() {
+        const RANKING: &str = r#"{"leaderboard": {"leaderboard_id": "l-1"}, "start": 0, "end": 1,
+            "items": [{"player": {"user_id": "p-1", "nickname": "alpha"}, "position": 1, "points": 42}]}"#;
+        let (base_url, requests) = mock_server(vec![(200, RANKING), (200, RANKING)]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        let hub = crate::http::ergonomic::Hub::new("h-1", &client);
+
+        let general = hub.general_leaderboard(None, Some(50)).await.unwrap();
+        assert_eq!(general.items[0].points, Some(42));
+        let season = hub.leaderboard("l-1", Some(0), None).await.unwrap();
+        assert_eq!(season.leaderboard.leaderboard_id, "l-1");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET /data/v4/leaderboards/hubs/h-1/general?limit=50 HTTP/1.1",
+                "GET /data/v4/leaderboards/l-1?offset=0 HTTP/1.1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_match_or_history() {
+        let (base_url, requests) = mock_server(vec![(404, r#"{"errors": []}"#)]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        let entry: MatchHistory = serde_json::from_str(
+            r#"{
+                "match_id": "m-1", "game_id": "csgo", "status": "finished", "game_mode": "5v5",
+                "started_at": 1500000000, "finished_at": 1500002400,
+                "results": {"score": {"faction1": 16, "faction2": 9}, "winner": "faction1"},
+                "teams": {"faction1": {"team_id": "t-1", "nickname": "team_alpha",
+                    "players": [{"player_id": "p-1", "nickname": "alpha", "skill_level": 10}]}}
+            }"#,
+        )
+        .unwrap();
+
+        let match_data = client.get_match_or_history(&entry).await.unwrap();
+        assert_eq!(match_data.match_id, "m-1");
+        assert_eq!(match_data.game, "csgo");
+        assert_eq!(match_data.duration(), Some(Duration::from_secs(2400)));
+        assert_eq!(
+            match_data.extra("game_mode"),
+            Some(&serde_json::json!("5v5"))
+        );
+        let roster = match_data.roster();
+        assert_eq!(roster.len(), 1);
+        assert_eq!(roster[0].game_skill_level, Some(10));
+        assert_eq!(
+            match_data.teams.unwrap()["faction1"].name.as_deref(),
+            Some("team_alpha")
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/matches/m-1 HTTP/1.1"]
+        );
+    }
+
+    #[cfg(feature = "ergonomic")]
+    #[tokio::test]
+    async fn test_game_all_matchmakings() {
+        // Regions are queried concurrently, so responses may arrive in any order
+        let (base_url, requests) = mock_server(vec![
+            (
+                200,
+                r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2", "regions": ["EU", "US"]}"#,
+            ),
+            (
+                200,
+                r#"{"start": 0, "end": 2, "items": [
+                    {"id": "mm-global", "name": "Global", "game": "cs2"},
+                    {"id": "mm-1", "name": "5v5", "game": "cs2", "region": "EU"}
+                ]}"#,
+            ),
+            (
+                200,
+                r#"{"start": 0, "end": 2, "items": [
+                    {"id": "mm-global", "name": "Global", "game": "cs2"},
+                    {"id": "mm-2", "name": "5v5", "game": "cs2", "region": "US"}
+                ]}"#,
+            ),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let matchmakings = crate::http::ergonomic::Game::new("cs2", &client)
+            .all_matchmakings()
+            .await
+            .unwrap();
+        let mut ids: Vec<&str> = matchmakings.iter().map(|mm| mm.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, ["mm-1", "mm-2", "mm-global"]);
+
+        let mut paths = requests.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "GET /data/v4/games/cs2 HTTP/1.1",
+                "GET /data/v4/games/cs2/matchmakings?region=EU&offset=0&limit=100 HTTP/1.1",
+                "GET /data/v4/games/cs2/matchmakings?region=US&offset=0&limit=100 HTTP/1.1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_hydrate() {
+        let results: UsersSearchList = serde_json::from_str(
+            r#"{"start": 0, "end": 2, "items": [
+                {"player_id": "p-1", "nickname": "alpha", "games": []},
+                {"player_id": "p-2", "nickname": "bravo", "games": []}
+            ]}"#,
+        )
+        .unwrap();
+
+        let (base_url, requests) = mock_server(vec![
+            (200, r#"{"player_id": "p-1", "nickname": "alpha"}"#),
+            (404, r#"{"errors": [{"message": "not found"}]}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        let players = results.hydrate(&client).await.unwrap();
+        assert_eq!(players.len(), 1);
+        let mut requests = requests.lock().unwrap().clone();
+        requests.sort();
+        assert_eq!(
+            requests,
+            [
+                "GET /data/v4/players/p-1 HTTP/1.1",
+                "GET /data/v4/players/p-2 HTTP/1.1"
+            ]
+        );
+
+        let (base_url, _) = mock_server(vec![
+            (404, r#"{"errors": [{"message": "not found"}]}"#),
+            (404, r#"{"errors": [{"message": "not found"}]}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        assert!(results.hydrate(&client).await.unwrap().is_empty());
+
+        // A failing profile is reported, not dropped like a missing one
+        let (base_url, _) = mock_server(vec![
+            (404, r#"{"errors": [{"message": "not found"}]}"#),
+            (500, r#"{"errors": [{"message": "internal error"}]}"#),
+        ])
+        .await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        assert_eq!(
+            results.hydrate(&client).await.unwrap_err().status(),
+            Some(500)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency() {
+        #[derive(Default)]
+        struct InFlightCounter(Mutex<(usize, usize)>);
+
+        impl Metrics for InFlightCounter {
+            fn on_request_start(&self, _path: &str) {
+                let mut counts = self.0.lock().unwrap();
+                counts.0 += 1;
+                counts.1 = counts.1.max(counts.0);
+            }
+
+            fn on_request_end(&self, _path: &str, _status: Option<u16>, _elapsed: Duration) {
+                self.0.lock().unwrap().0 -= 1;
+            }
+        }
+
+        let player = r#"{"player_id": "p-1", "nickname": "alpha"}"#;
+        let (base_url, requests) = mock_server(vec![(200, player); 3]).await;
+        let counter = Arc::new(InFlightCounter::default());
+        let client = ClientBuilder::new()
+            .base_url(base_url)
+            .metrics(counter.clone())
+            .max_concurrency(1)
+            .build()
+            .unwrap();
+
+        let results =
+            futures_util::future::join_all(["p-1", "p-2", "p-3"].map(|id| client.get_player(id)))
+                .await;
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+        assert_eq!(*counter.0.lock().unwrap(), (0, 1));
+        assert!(format!("{:?}", client).contains("max_concurrency: Some(1)"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_many_dedupes() {
+        let (base_url, requests) =
+            mock_server(vec![(200, r#"{"player_id": "p-1", "nickname": "alpha"}"#)]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+
+        let players = Player::resolve_many(&client, ["p-1", "p-1"]).await.unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players["p-1"].nickname, "alpha");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /data/v4/players/p-1 HTTP/1.1"]
+        );
+
+        let (base_url, _) =
+            mock_server(vec![(404, r#"{"errors": []}"#), (401, r#"{"errors": []}"#)]).await;
+        let client = ClientBuilder::new().base_url(base_url).build().unwrap();
+        assert!(
+            Player::resolve_many(&client, ["p-1"])
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let error = Player::resolve_many(&client, ["p-1"]).await.unwrap_err();
+        assert!(matches!(error.inner(), Error::InvalidApiKey));
     }
 
     #[test]
@@ -0,0 +1,99 @@
+//! Single-flight request coalescing
+//!
+//! Concurrent requests for the same URL share one underlying HTTP request.
+//! Enabled with [`ClientBuilder::coalesce_requests`](super::ClientBuilder::coalesce_requests).
+
+use super::client::RawResponse;
+use crate::error::Error;
+use futures_util::future::{BoxFuture, FutureExt, Shared, TryFutureExt};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+type SharedResponse = Shared<BoxFuture<'static, Result<RawResponse, Arc<Error>>>>;
+
+/// Requests currently in flight, keyed by URL
+#[derive(Default)]
+pub(crate) struct InFlight {
+    requests: Mutex<HashMap<String, SharedResponse>>,
+}
+
+impl InFlight {
+    /// Await `request`, or the identical request already in flight for `url`
+    ///
+    /// `request` is only polled if no request for `url` is in flight. Once the
+    /// shared request completes, the next call for `url` sends a new one. If
+    /// every caller waiting on a request is dropped, the request is dropped
+    /// (and cancelled) with them.
+    pub(crate) async fn run<F>(&self, url: &str, request: F) -> Result<RawResponse, Error>
+    where
+        F: Future<Output = Result<RawResponse, Error>> + Send + 'static,
+    {
+        let shared = self
+            .lock()
+            .entry(url.to_string())
+            .or_insert_with(|| request.map_err(Arc::new).boxed().shared())
+            .clone();
+        let mut waiter = Waiter {
+            in_flight: self,
+            url,
+            waiting: Some(shared.clone()),
+            shared,
+            done: false,
+        };
+
+        let result = waiter
+            .waiting
+            .as_mut()
+            .expect("waiting until the waiter is dropped")
+            .await;
+        waiter.done = true;
+        drop(waiter);
+
+        result.map_err(|e| Arc::try_unwrap(e).unwrap_or_else(|e| shared_error(&e)))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, SharedResponse>> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A caller waiting on a shared request
+///
+/// Removes the request from the map when dropped, either because it completed
+/// or because the last caller waiting on it was cancelled.
+struct Waiter<'a> {
+    in_flight: &'a InFlight,
+    url: &'a str,
+    /// Handle being awaited; `None` once dropped
+    waiting: Option<SharedResponse>,
+    /// Handle kept to identify the map entry
+    shared: SharedResponse,
+    done: bool,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        self.waiting = None;
+        let mut requests = self.in_flight.lock();
+        let is_entry = requests
+            .get(self.url)
+            .is_some_and(|entry| entry.ptr_eq(&self.shared));
+        // Handles left: the map entry and `self.shared`, unless others still wait
+        let abandoned = self.shared.strong_count() == Some(2);
+        if is_entry && (self.done || abandoned) {
+            requests.remove(self.url);
+        }
+    }
+}
+
+/// Copy a transport error for another caller of a shared request
+///
+/// [`Error::Http`] wraps a non-cloneable `reqwest::Error`, so it is reported
+/// to the other callers as [`Error::Connection`] wrapping the shared error.
+fn shared_error(error: &Arc<Error>) -> Error {
+    match error.as_ref() {
+        Error::Timeout => Error::Timeout,
+        Error::Connection(source) => Error::Connection(source.clone()),
+        _ => Error::Connection(error.clone()),
+    }
+}
@@ -0,0 +1,113 @@
+//! Client-wide limit on requests in flight
+//!
+//! Enabled with [`ClientBuilder::max_concurrency`](super::ClientBuilder::max_concurrency).
+//! Every request holds a permit while it is sent and its body is read, so the
+//! limit covers batch helpers and independent callers alike.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// An async semaphore without a runtime dependency
+#[derive(Debug)]
+pub(crate) struct ConcurrencyLimit {
+    max: usize,
+    state: Mutex<LimitState>,
+}
+
+#[derive(Debug, Default)]
+struct LimitState {
+    in_use: usize,
+    next_waiter: u64,
+    waiters: HashMap<u64, Waker>,
+}
+
+impl ConcurrencyLimit {
+    /// Allow `max` requests at once; `0` is treated as `1`
+    pub(crate) fn new(max: usize) -> Self {
+        Self {
+            max: max.max(1),
+            state: Mutex::new(LimitState::default()),
+        }
+    }
+
+    pub(crate) fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Wait for a free slot
+    ///
+    /// The slot is released when the returned permit is dropped.
+    pub(crate) fn acquire(self: Arc<Self>) -> Acquire {
+        Acquire {
+            limit: self,
+            waiter: None,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LimitState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Future returned by [`ConcurrencyLimit::acquire`]
+pub(crate) struct Acquire {
+    limit: Arc<ConcurrencyLimit>,
+    /// Key of this future's waker while it is waiting
+    waiter: Option<u64>,
+}
+
+impl Future for Acquire {
+    type Output = Permit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Permit> {
+        let this = &mut *self;
+        let mut state = this.limit.lock();
+        if state.in_use < this.limit.max {
+            state.in_use += 1;
+            if let Some(waiter) = this.waiter.take() {
+                state.waiters.remove(&waiter);
+            }
+            drop(state);
+            return Poll::Ready(Permit {
+                limit: this.limit.clone(),
+            });
+        }
+
+        let waiter = *this.waiter.get_or_insert_with(|| {
+            state.next_waiter += 1;
+            state.next_waiter
+        });
+        state.waiters.insert(waiter, cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        if let Some(waiter) = self.waiter {
+            self.limit.lock().waiters.remove(&waiter);
+        }
+    }
+}
+
+/// A slot taken from a [`ConcurrencyLimit`]
+pub(crate) struct Permit {
+    limit: Arc<ConcurrencyLimit>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        // Wake every waiter: one of them takes the slot, the others wait again.
+        // Waking only one could lose the slot if that waiter is dropped first.
+        let waiters: Vec<Waker> = {
+            let mut state = self.limit.lock();
+            state.in_use -= 1;
+            state.waiters.drain().map(|(_, waker)| waker).collect()
+        };
+        for waker in waiters {
+            waker.wake();
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::error::Error;
 use crate::http::Client;
+use crate::http::resolve::resolve_ordered;
 use crate::types::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 
 /// High-level API for interacting with a specific championship
 ///
@@ -10,7 +13,7 @@ use crate::types::*;
 /// # Examples
 ///
 /// ```no_run
-/// # use faceit::{HttpClient, http::ergonomic::Championship};
+/// # use faceit::{HttpClient, http::{MatchType, ergonomic::Championship}};
 /// # async fn example() -> Result<(), faceit::error::Error> {
 /// let client = HttpClient::new();
 /// let championship = Championship::new("championship-id-here", &client);
@@ -20,18 +23,21 @@ use crate::types::*;
 /// println!("Championship: {}", championship_data.name);
 ///
 /// // Get championship matches
-/// let matches = championship.matches(Some("all"), Some(0), Some(20)).await?;
+/// let matches = championship.matches(Some(MatchType::All), Some(0), Some(20)).await?;
 /// # Ok(())
 /// # }
 /// ```
 pub struct Championship<'a> {
-    championship_id: String,
+    championship_id: Cow<'a, str>,
     client: &'a Client,
 }
 
 impl<'a> Championship<'a> {
     /// Create a new Championship instance
     ///
+    /// Accepts a `&str` or a `String`; a borrowed ID is stored without
+    /// allocating.
+    ///
     /// # Arguments
     /// * `championship_id` - The championship ID
     /// * `client` - Reference to the FACEIT client
@@ -43,7 +49,7 @@ impl<'a> Championship<'a> {
     /// let client = HttpClient::new();
     /// let championship = Championship::new("championship-id-here", &client);
     /// ```
-    pub fn new(championship_id: impl Into<String>, client: &'a Client) -> Self {
+    pub fn new(championship_id: impl Into<Cow<'a, str>>, client: &'a Client) -> Self {
         Self {
             championship_id: championship_id.into(),
             client,
@@ -84,24 +90,24 @@ impl<'a> Championship<'a> {
     /// Get the championship's matches
     ///
     /// # Arguments
-    /// * `match_type` - Optional match type filter ("all", "upcoming", "ongoing", "past")
+    /// * `match_type` - Optional [`MatchType`](crate::http::MatchType) filter (default: all)
     /// * `offset` - Optional offset for pagination (default: 0)
     /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use faceit::{HttpClient, http::ergonomic::Championship};
+    /// # use faceit::{HttpClient, http::{MatchType, ergonomic::Championship}};
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
     /// let championship = Championship::new("championship-id-here", &client);
-    /// let matches = championship.matches(Some("all"), Some(0), Some(20)).await?;
+    /// let matches = championship.matches(Some(MatchType::All), Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn matches(
         &self,
-        match_type: Option<&str>,
+        match_type: Option<crate::http::MatchType>,
         offset: Option<i64>,
         limit: Option<i64>,
     ) -> Result<MatchesList, Error> {
@@ -109,4 +115,127 @@ impl<'a> Championship<'a> {
             .get_championship_matches(&self.championship_id, match_type, offset, limit)
             .await
     }
+
+    /// Like [`matches`](Self::matches), with the match type as a string
+    ///
+    /// See [`Client::get_championship_matches_str`](crate::http::Client::get_championship_matches_str).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Championship};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championship = Championship::new("championship-id-here", &client);
+    /// let matches = championship.matches_str(Some("past"), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn matches_str(
+        &self,
+        match_type: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<MatchesList, Error> {
+        self.client
+            .get_championship_matches_str(&self.championship_id, match_type, offset, limit)
+            .await
+    }
+
+    /// Get all of the championship's matches grouped into a bracket
+    ///
+    /// Fetches every match (see [`Client::get_all_championship_matches`]) and
+    /// groups them by `(round, group)`, ordered by round and then group.
+    /// Matches within a group keep the order the API returned them in.
+    /// Matches without a round or group are keyed with `0` in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Championship};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championship = Championship::new("championship-id-here", &client);
+    /// for ((round, group), matches) in championship.bracket().await? {
+    ///     println!("Round {round}, group {group}: {} matches", matches.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bracket(&self) -> Result<BTreeMap<(i64, i64), Vec<Match>>, Error> {
+        let matches = self
+            .client
+            .get_all_championship_matches(&self.championship_id)
+            .await?;
+
+        let mut bracket: BTreeMap<(i64, i64), Vec<Match>> = BTreeMap::new();
+        for match_data in matches {
+            let key = (match_data.round.unwrap_or(0), match_data.group.unwrap_or(0));
+            bracket.entry(key).or_default().push(match_data);
+        }
+
+        Ok(bracket)
+    }
+
+    /// Get the teams subscribed to the championship
+    ///
+    /// # Arguments
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 10, max: 10)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Championship};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championship = Championship::new("championship-id-here", &client);
+    /// let subscriptions = championship.subscriptions(Some(0), Some(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscriptions(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<ChampionshipSubscriptionsList, Error> {
+        self.client
+            .get_championship_subscriptions(&self.championship_id, offset, limit)
+            .await
+    }
+
+    /// Get full team details for every subscribed team
+    ///
+    /// Fetches all subscriptions, then each subscribed team with
+    /// [`Client::get_team`]. Teams are returned in subscription order; teams
+    /// that have since been deleted are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscriptions cannot be fetched, or the first
+    /// team error other than a `404 Not Found`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Championship};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championship = Championship::new("championship-id-here", &client);
+    /// for team in championship.teams_full().await? {
+    ///     println!("{} ({} members)", team.name, team.members.map_or(0, |m| m.len()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn teams_full(&self) -> Result<Vec<Team>, Error> {
+        let subscriptions = self
+            .client
+            .get_all_championship_subscriptions(&self.championship_id)
+            .await?;
+        resolve_ordered(&subscriptions, |subscription| {
+            self.client.get_team(&subscription.team.team_id)
+        })
+        .await
+    }
 }
@@ -1,6 +1,8 @@
 use crate::error::Error;
 use crate::http::Client;
 use crate::types::*;
+use std::borrow::Cow;
+use std::collections::HashSet;
 
 /// High-level API for interacting with a specific game
 ///
@@ -25,13 +27,16 @@ use crate::types::*;
 /// # }
 /// ```
 pub struct Game<'a> {
-    game_id: String,
+    game_id: Cow<'a, str>,
     client: &'a Client,
 }
 
 impl<'a> Game<'a> {
     /// Create a new Game instance
     ///
+    /// Accepts a `&str` or a `String`; a borrowed ID is stored without
+    /// allocating.
+    ///
     /// # Arguments
     /// * `game_id` - The game ID (e.g., "cs2", "csgo")
     /// * `client` - Reference to the FACEIT client
@@ -43,7 +48,7 @@ impl<'a> Game<'a> {
     /// let client = HttpClient::new();
     /// let game = Game::new("cs2", &client);
     /// ```
-    pub fn new(game_id: impl Into<String>, client: &'a Client) -> Self {
+    pub fn new(game_id: impl Into<Cow<'a, str>>, client: &'a Client) -> Self {
         Self {
             game_id: game_id.into(),
             client,
@@ -118,4 +123,53 @@ impl<'a> Game<'a> {
             .get_game_matchmakings(&self.game_id, region, offset, limit)
             .await
     }
+    /// Get the game's matchmakings across all of its regions
+    ///
+    /// Fetches the game to learn its regions, then fetches every region's
+    /// matchmakings concurrently (see [`Client::get_all_game_matchmakings`])
+    /// and merges them. Matchmakings listed under several regions are
+    /// returned once. Games without regions are queried without a region
+    /// filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game or any region's matchmakings cannot be
+    /// fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Game};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let game = Game::new("cs2", &client);
+    /// for matchmaking in game.all_matchmakings().await? {
+    ///     println!("{} ({:?})", matchmaking.name, matchmaking.region);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_matchmakings(&self) -> Result<Vec<MatchmakingSlim>, Error> {
+        let game = self.get().await?;
+        let regions: Vec<Option<&str>> = match game.regions.as_deref() {
+            Some(regions) if !regions.is_empty() => {
+                regions.iter().map(|r| Some(r.as_str())).collect()
+            }
+            _ => vec![None],
+        };
+
+        let per_region = futures_util::future::try_join_all(
+            regions
+                .into_iter()
+                .map(|region| self.client.get_all_game_matchmakings(&self.game_id, region)),
+        )
+        .await?;
+
+        let mut seen = HashSet::new();
+        Ok(per_region
+            .into_iter()
+            .flatten()
+            .filter(|matchmaking| seen.insert(matchmaking.id.clone()))
+            .collect())
+    }
 }
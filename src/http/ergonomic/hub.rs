@@ -1,6 +1,10 @@
 use crate::error::Error;
 use crate::http::Client;
 use crate::types::*;
+use std::borrow::Cow;
+
+/// Every entity the hub endpoint can expand, requested by [`Hub::get_expanded`]
+const HUB_EXPANSIONS: &[&str] = &["organizer", "game"];
 
 /// High-level API for interacting with a specific hub
 ///
@@ -10,7 +14,7 @@ use crate::types::*;
 /// # Examples
 ///
 /// ```no_run
-/// # use faceit::{HttpClient, http::ergonomic::Hub};
+/// # use faceit::{HttpClient, http::{MatchType, ergonomic::Hub}};
 /// # async fn example() -> Result<(), faceit::error::Error> {
 /// let client = HttpClient::new();
 /// let hub = Hub::new("hub-id-here", &client);
@@ -20,18 +24,21 @@ use crate::types::*;
 /// println!("Hub: {}", hub_data.name);
 ///
 /// // Get hub matches
-/// let matches = hub.matches(Some("all"), Some(0), Some(20)).await?;
+/// let matches = hub.matches(Some(MatchType::All), Some(0), Some(20)).await?;
 /// # Ok(())
 /// # }
 /// ```
 pub struct Hub<'a> {
-    hub_id: String,
+    hub_id: Cow<'a, str>,
     client: &'a Client,
 }
 
 impl<'a> Hub<'a> {
     /// Create a new Hub instance
     ///
+    /// Accepts a `&str` or a `String`; a borrowed ID is stored without
+    /// allocating.
+    ///
     /// # Arguments
     /// * `hub_id` - The hub ID
     /// * `client` - Reference to the FACEIT client
@@ -43,7 +50,7 @@ impl<'a> Hub<'a> {
     /// let client = HttpClient::new();
     /// let hub = Hub::new("hub-id-here", &client);
     /// ```
-    pub fn new(hub_id: impl Into<String>, client: &'a Client) -> Self {
+    pub fn new(hub_id: impl Into<Cow<'a, str>>, client: &'a Client) -> Self {
         Self {
             hub_id: hub_id.into(),
             client,
@@ -76,27 +83,51 @@ impl<'a> Hub<'a> {
         self.client.get_hub(&self.hub_id, expanded).await
     }
 
+    /// Get the hub's details with every related entity expanded
+    ///
+    /// Equivalent to [`get`](Self::get) with `["organizer", "game"]`, so
+    /// [`organizer_data`](crate::types::Hub::organizer_data) and
+    /// [`game_data`](crate::types::Hub::game_data) are populated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let hub_data = hub.get_expanded().await?;
+    /// if let Some(organizer) = &hub_data.organizer_data {
+    ///     println!("{} is run by {}", hub_data.name, organizer.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_expanded(&self) -> Result<crate::types::Hub, Error> {
+        self.get(Some(HUB_EXPANSIONS)).await
+    }
+
     /// Get the hub's matches
     ///
     /// # Arguments
-    /// * `match_type` - Optional match type filter ("all", "upcoming", "ongoing", "past")
+    /// * `match_type` - Optional [`MatchType`](crate::http::MatchType) filter (default: all)
     /// * `offset` - Optional offset for pagination (default: 0)
     /// * `limit` - Optional limit for pagination (default: 20, max: 100)
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # use faceit::{HttpClient, http::{MatchType, ergonomic::Hub}};
     /// # async fn example() -> Result<(), faceit::error::Error> {
     /// let client = HttpClient::new();
     /// let hub = Hub::new("hub-id-here", &client);
-    /// let matches = hub.matches(Some("all"), Some(0), Some(20)).await?;
+    /// let matches = hub.matches(Some(MatchType::All), Some(0), Some(20)).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn matches(
         &self,
-        match_type: Option<&str>,
+        match_type: Option<crate::http::MatchType>,
         offset: Option<i64>,
         limit: Option<i64>,
     ) -> Result<MatchesList, Error> {
@@ -105,6 +136,87 @@ impl<'a> Hub<'a> {
             .await
     }
 
+    /// Like [`matches`](Self::matches), with the match type as a string
+    ///
+    /// See [`Client::get_hub_matches_str`](crate::http::Client::get_hub_matches_str).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let matches = hub.matches_str(Some("past"), Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn matches_str(
+        &self,
+        match_type: Option<&str>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<MatchesList, Error> {
+        self.client
+            .get_hub_matches_str(&self.hub_id, match_type, offset, limit)
+            .await
+    }
+
+    /// Get the hub's past matches started within a time window
+    ///
+    /// # Arguments
+    /// * `from` - Start of the window (Unix time, inclusive)
+    /// * `to` - End of the window (Unix time, exclusive)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let week = hub.matches_between(1_700_000_000, 1_700_604_800).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn matches_between(&self, from: i64, to: i64) -> Result<Vec<Match>, Error> {
+        self.client
+            .get_hub_matches_between(&self.hub_id, from, to)
+            .await
+    }
+
+    /// Get a report of the hub's matches started within a time window
+    ///
+    /// See [`Client::get_hub_report`].
+    ///
+    /// # Arguments
+    /// * `from` - Start of the window (Unix time, inclusive)
+    /// * `to` - End of the window (Unix time, exclusive)
+    /// * `include_stats` - Whether to fetch statistics for each match
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let report = hub.report(1_700_000_000, 1_700_604_800, true).await?;
+    /// println!("{} matches", report.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report(
+        &self,
+        from: i64,
+        to: i64,
+        include_stats: bool,
+    ) -> Result<HubReport, Error> {
+        self.client
+            .get_hub_report(&self.hub_id, from, to, include_stats)
+            .await
+    }
+
     /// Get the hub's members
     ///
     /// # Arguments
@@ -132,6 +244,177 @@ impl<'a> Hub<'a> {
             .await
     }
 
+    /// Get the hub's roles
+    ///
+    /// # Arguments
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 50)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let roles = hub.roles(None, Some(50)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn roles(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<HubRolesList, Error> {
+        self.client.get_hub_roles(&self.hub_id, offset, limit).await
+    }
+
+    /// Get the hub's members together with their resolved roles
+    ///
+    /// Fetches all members (see [`Client::get_all_hub_members`]) and every
+    /// page of the hub's role catalog (see [`Client::get_all_hub_roles`]),
+    /// concurrently, then looks up each member's role IDs with
+    /// [`HubUser::resolve_roles`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// for (member, roles) in hub.members_detailed().await? {
+    ///     let names: Vec<&str> = roles.iter().map(|role| role.name.as_str()).collect();
+    ///     println!("{}: {}", member.nickname, names.join(", "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn members_detailed(&self) -> Result<Vec<(HubUser, Vec<HubRole>)>, Error> {
+        let (members, roles) = futures_util::future::try_join(
+            self.client.get_all_hub_members(&self.hub_id),
+            self.client.get_all_hub_roles(&self.hub_id),
+        )
+        .await?;
+
+        Ok(members
+            .into_iter()
+            .map(|member| {
+                let member_roles = member.resolve_roles(&roles);
+                (member, member_roles)
+            })
+            .collect())
+    }
+
+    /// Get the hub's leaderboards
+    ///
+    /// # Arguments
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// for leaderboard in hub.leaderboards(None, None).await? {
+    ///     println!("{:?}", leaderboard.leaderboard_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn leaderboards(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<LeaderboardsList, Error> {
+        self.client
+            .get_hub_leaderboards(&self.hub_id, offset, limit)
+            .await
+    }
+
+    /// Get the standings of one of the hub's leaderboards
+    ///
+    /// # Arguments
+    /// * `leaderboard_id` - The leaderboard ID (see [`leaderboards`](Self::leaderboards))
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let standings = hub.leaderboard("leaderboard-id", Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn leaderboard(
+        &self,
+        leaderboard_id: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<LeaderboardRanking, Error> {
+        self.client
+            .get_leaderboard(leaderboard_id, offset, limit)
+            .await
+    }
+
+    /// Get the hub's all-time standings
+    ///
+    /// # Arguments
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// for entry in hub.general_leaderboard(Some(0), Some(20)).await? {
+    ///     println!("#{} {} ({:?} points)", entry.position, entry.player.nickname, entry.points);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn general_leaderboard(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<LeaderboardRanking, Error> {
+        self.client
+            .get_hub_general_leaderboard(&self.hub_id, offset, limit)
+            .await
+    }
+
+    /// Check whether a player is a member of the hub
+    ///
+    /// See [`Client::is_hub_member`] for how membership is looked up.
+    ///
+    /// # Arguments
+    /// * `player_id` - The FACEIT player ID
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Hub};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let hub = Hub::new("hub-id-here", &client);
+    /// let is_member = hub.has_member("player-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn has_member(&self, player_id: &str) -> Result<bool, Error> {
+        self.client.is_hub_member(&self.hub_id, player_id).await
+    }
+
     /// Get the hub's statistics
     ///
     /// # Arguments
@@ -1,5 +1,13 @@
 use crate::error::Error;
 use crate::http::Client;
+use crate::http::resolve::resolve_ordered;
+#[cfg(feature = "tokio")]
+use crate::types::MatchStatus;
+#[cfg(feature = "tokio")]
+use futures_util::Stream;
+use std::borrow::Cow;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
 
 /// High-level API for interacting with a specific match
 ///
@@ -24,13 +32,16 @@ use crate::http::Client;
 /// # }
 /// ```
 pub struct Match<'a> {
-    match_id: String,
+    match_id: Cow<'a, str>,
     client: &'a Client,
 }
 
 impl<'a> Match<'a> {
     /// Create a new Match instance
     ///
+    /// Accepts a `&str` or a `String`; a borrowed ID is stored without
+    /// allocating.
+    ///
     /// # Arguments
     /// * `match_id` - The FACEIT match ID
     /// * `client` - Reference to the FACEIT client
@@ -42,7 +53,7 @@ impl<'a> Match<'a> {
     /// let client = HttpClient::new();
     /// let match_obj = Match::new("match-id-here", &client);
     /// ```
-    pub fn new(match_id: impl Into<String>, client: &'a Client) -> Self {
+    pub fn new(match_id: impl Into<Cow<'a, str>>, client: &'a Client) -> Self {
         Self {
             match_id: match_id.into(),
             client,
@@ -72,8 +83,142 @@ impl<'a> Match<'a> {
         self.client.get_match(&self.match_id).await
     }
 
+    /// Poll the match until it reaches a terminal status
+    ///
+    /// Calls [`get`](Self::get) every `poll_interval` until the match is
+    /// finished, cancelled or aborted, and returns the final match. When the API
+    /// responds with `429 Too Many Requests`, the poll interval is doubled
+    /// (up to one minute) instead of failing.
+    ///
+    /// Requires the `tokio` feature.
+    ///
+    /// # Arguments
+    /// * `poll_interval` - Delay between polls
+    /// * `timeout` - Maximum total time to wait
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WaitTimeout`] if the match is not over within `timeout`.
+    /// Returns any other error from [`get`](Self::get) as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Match};
+    /// # use std::time::Duration;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let match_obj = Match::new("match-id-here", &client);
+    /// let finished = match_obj
+    ///     .wait_for_finish(Duration::from_secs(30), Duration::from_secs(3 * 60 * 60))
+    ///     .await?;
+    /// println!("Final status: {}", finished.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn wait_for_finish(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<crate::types::Match, Error> {
+        const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+        let poll = async {
+            let mut interval = poll_interval;
+            loop {
+                match self.get().await {
+                    Ok(match_data) if match_data.status_kind().is_terminal() => {
+                        return Ok(match_data);
+                    }
+                    Ok(_) => interval = poll_interval,
+                    Err(e) if e.status() == Some(429) => interval = (interval * 2).min(MAX_BACKOFF),
+                    Err(e) => return Err(e),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, poll)
+            .await
+            .map_err(|_| Error::WaitTimeout(timeout))?
+    }
+
+    /// Watch the match for status changes
+    ///
+    /// Returns a stream that polls [`get`](Self::get) every `poll_interval` and
+    /// yields the match status whenever it differs from the previous poll. The
+    /// first item is the current status. The stream ends after yielding a
+    /// terminal status (finished, cancelled or aborted).
+    ///
+    /// Errors are yielded as they occur and polling continues, so callers can
+    /// decide whether to keep watching or stop.
+    ///
+    /// Requires the `tokio` feature.
+    ///
+    /// # Arguments
+    /// * `poll_interval` - Delay between polls
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Match};
+    /// # use std::time::Duration;
+    /// use futures_util::{pin_mut, StreamExt};
+    ///
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let match_obj = Match::new("match-id-here", &client);
+    ///
+    /// let updates = match_obj.watch(Duration::from_secs(15));
+    /// pin_mut!(updates);
+    /// while let Some(status) = updates.next().await {
+    ///     println!("Match is now {}", status?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn watch(&self, poll_interval: Duration) -> impl Stream<Item = Result<MatchStatus, Error>> {
+        // State: (last status seen, whether we have polled before, whether the stream is done)
+        futures_util::stream::unfold(
+            (None::<MatchStatus>, false, false),
+            move |(mut last, polled, done)| async move {
+                if done {
+                    return None;
+                }
+                if polled {
+                    tokio::time::sleep(poll_interval).await;
+                }
+
+                loop {
+                    match self.get().await {
+                        Ok(match_data) => {
+                            let status = match_data.status_kind();
+                            if last.as_ref() != Some(&status) {
+                                let done = status.is_terminal();
+                                return Some((Ok(status.clone()), (Some(status), true, done)));
+                            }
+                            last = Some(status);
+                        }
+                        Err(e) => return Some((Err(e), (last, true, false))),
+                    }
+                    tokio::time::sleep(poll_interval).await;
+                }
+            },
+        )
+    }
+
     /// Get the match's statistics
     ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StatsNotReady`] if the API has no statistics for the
+    /// match because it has not finished (see
+    /// [`stats_available`](crate::types::Match::stats_available)). The match is
+    /// only fetched to check this after the statistics request returned
+    /// `404 Not Found`. Other errors are returned as-is.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -87,6 +232,87 @@ impl<'a> Match<'a> {
     /// # }
     /// ```
     pub async fn stats(&self) -> Result<crate::types::MatchStats, Error> {
-        self.client.get_match_stats(&self.match_id).await
+        match self.client.get_match_stats(&self.match_id).await {
+            Err(e) if e.is_not_found() => match self.get().await {
+                Ok(match_data) if !match_data.stats_available() => {
+                    Err(Error::StatsNotReady(match_data.status))
+                }
+                _ => Err(e),
+            },
+            result => result,
+        }
+    }
+
+    /// Get the match's details and statistics in one call
+    ///
+    /// Both are fetched concurrently. Statistics only exist once a match has
+    /// finished; if the API reports them as not found, `None` is returned
+    /// alongside the match instead of an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the match cannot be fetched, or if fetching the
+    /// statistics fails for any reason other than a `404 Not Found`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Match};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let match_obj = Match::new("match-id-here", &client);
+    /// let (match_data, stats) = match_obj.full().await?;
+    /// match stats {
+    ///     Some(stats) => println!("{}: {} rounds", match_data.match_id, stats.rounds.len()),
+    ///     None => println!("{}: {}", match_data.match_id, match_data.status),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn full(
+        &self,
+    ) -> Result<(crate::types::Match, Option<crate::types::MatchStats>), Error> {
+        let (match_data, stats) =
+            futures_util::future::join(self.get(), self.client.get_match_stats(&self.match_id))
+                .await;
+        let stats = match stats {
+            Ok(stats) => Some(stats),
+            Err(e) if e.is_not_found() => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok((match_data?, stats))
+    }
+
+    /// Get full player profiles for everyone on the match rosters
+    ///
+    /// Fetches the match, then each roster member's profile with
+    /// [`Client::get_player`]. Players are returned in roster order (see
+    /// [`crate::types::Match::roster`]); deleted profiles are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the match cannot be fetched, or the first profile
+    /// error other than a `404 Not Found`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Match};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let match_obj = Match::new("match-id-here", &client);
+    /// for player in match_obj.full_rosters().await? {
+    ///     println!("{} ({:?})", player.nickname, player.country);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn full_rosters(&self) -> Result<Vec<crate::types::Player>, Error> {
+        let match_data = self.get().await?;
+        resolve_ordered(match_data.roster(), |member| {
+            self.client.get_player(&member.player_id)
+        })
+        .await
     }
 }
@@ -2,10 +2,12 @@ mod championship;
 mod game;
 mod hub;
 mod r#match;
+mod organizer;
 mod player;
 
 pub use championship::Championship;
 pub use game::Game;
 pub use hub::Hub;
 pub use r#match::Match;
+pub use organizer::Organizer;
 pub use player::Player;
@@ -0,0 +1,145 @@
+use crate::error::Error;
+use crate::http::Client;
+use crate::types::*;
+use std::borrow::Cow;
+
+/// High-level API for interacting with a specific organizer
+///
+/// This struct provides a convenient way to work with organizer data
+/// without needing to pass the organizer ID to each method call.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use faceit::{HttpClient, http::ergonomic::Organizer};
+/// # async fn example() -> Result<(), faceit::error::Error> {
+/// let client = HttpClient::new();
+/// let organizer = Organizer::new("organizer-id-here", &client);
+///
+/// // Get the organizer with its hubs and active championships
+/// let overview = organizer.overview().await?;
+/// println!("Organizer: {}", overview.organizer.name);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Organizer<'a> {
+    organizer_id: Cow<'a, str>,
+    client: &'a Client,
+}
+
+impl<'a> Organizer<'a> {
+    /// Create a new Organizer instance
+    ///
+    /// Accepts a `&str` or a `String`; a borrowed ID is stored without
+    /// allocating.
+    ///
+    /// # Arguments
+    /// * `organizer_id` - The organizer ID
+    /// * `client` - Reference to the FACEIT client
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Organizer};
+    /// let client = HttpClient::new();
+    /// let organizer = Organizer::new("organizer-id-here", &client);
+    /// ```
+    pub fn new(organizer_id: impl Into<Cow<'a, str>>, client: &'a Client) -> Self {
+        Self {
+            organizer_id: organizer_id.into(),
+            client,
+        }
+    }
+
+    /// Get the organizer's ID
+    pub fn id(&self) -> &str {
+        &self.organizer_id
+    }
+
+    /// Get the organizer's details
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Organizer};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let organizer = Organizer::new("organizer-id-here", &client);
+    /// let organizer_data = organizer.get().await?;
+    /// println!("Organizer: {}", organizer_data.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> Result<crate::types::Organizer, Error> {
+        self.client.get_organizer(&self.organizer_id).await
+    }
+
+    /// Get the organizer's hubs
+    ///
+    /// # Arguments
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 50)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Organizer};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let organizer = Organizer::new("organizer-id-here", &client);
+    /// let hubs = organizer.hubs(Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn hubs(&self, offset: Option<i64>, limit: Option<i64>) -> Result<HubsList, Error> {
+        self.client
+            .get_organizer_hubs(&self.organizer_id, offset, limit)
+            .await
+    }
+
+    /// Get the organizer's championships
+    ///
+    /// # Arguments
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Organizer};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let organizer = Organizer::new("organizer-id-here", &client);
+    /// let championships = organizer.championships(Some(0), Some(20)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn championships(
+        &self,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<ChampionshipsList, Error> {
+        self.client
+            .get_organizer_championships(&self.organizer_id, offset, limit)
+            .await
+    }
+
+    /// Get the organizer together with its hubs and active championships
+    ///
+    /// See [`Client::get_organizer_overview`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Organizer};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let organizer = Organizer::new("organizer-id-here", &client);
+    /// let overview = organizer.overview().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn overview(&self) -> Result<OrganizerOverview, Error> {
+        self.client.get_organizer_overview(&self.organizer_id).await
+    }
+}
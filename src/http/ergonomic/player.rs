@@ -1,6 +1,7 @@
 use crate::error::Error;
-use crate::http::Client;
+use crate::http::{Client, PlayerHistoryParams};
 use crate::types::*;
+use std::borrow::Cow;
 
 /// High-level API for interacting with a specific player
 ///
@@ -28,13 +29,16 @@ use crate::types::*;
 /// # }
 /// ```
 pub struct Player<'a> {
-    player_id: String,
+    player_id: Cow<'a, str>,
     client: &'a Client,
 }
 
 impl<'a> Player<'a> {
     /// Create a new Player instance
     ///
+    /// Accepts a `&str` or a `String`; a borrowed ID is stored without
+    /// allocating.
+    ///
     /// # Arguments
     /// * `player_id` - The FACEIT player ID
     /// * `client` - Reference to the FACEIT client
@@ -46,7 +50,7 @@ impl<'a> Player<'a> {
     /// let client = HttpClient::new();
     /// let player = Player::new("player-id-here", &client);
     /// ```
-    pub fn new(player_id: impl Into<String>, client: &'a Client) -> Self {
+    pub fn new(player_id: impl Into<Cow<'a, str>>, client: &'a Client) -> Self {
         Self {
             player_id: player_id.into(),
             client,
@@ -96,6 +100,111 @@ impl<'a> Player<'a> {
         self.client.get_player_stats(&self.player_id, game_id).await
     }
 
+    /// Get the player's best placement in the global rankings of a game
+    ///
+    /// The ranking endpoints need a region, so this fetches the player,
+    /// queries every region they are known to play in (see
+    /// [`GameDetail::known_regions`]) concurrently, and returns the entry with
+    /// the lowest position. Regions the player is not ranked in are skipped.
+    ///
+    /// Returns `None` if the player has not played the game or is not ranked
+    /// in any of their regions.
+    ///
+    /// # Arguments
+    /// * `game_id` - The game ID (e.g., "cs2", "csgo")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the player cannot be fetched, or if a ranking
+    /// request fails for any reason other than a `404 Not Found`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// if let Some(ranking) = player.best_global_ranking("cs2").await? {
+    ///     println!("#{} with {} ELO", ranking.position, ranking.faceit_elo);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn best_global_ranking(&self, game_id: &str) -> Result<Option<GlobalRanking>, Error> {
+        let player = self.get().await?;
+        let Some(detail) = player.game_detail(game_id) else {
+            return Ok(None);
+        };
+
+        let rankings =
+            futures_util::future::join_all(detail.known_regions().into_iter().map(|region| {
+                self.client
+                    .get_player_ranking(game_id, region, &self.player_id, None, None)
+            }))
+            .await;
+
+        let mut best: Option<GlobalRanking> = None;
+        for ranking in rankings {
+            let ranking = match ranking {
+                Ok(ranking) => ranking,
+                Err(e) if e.is_not_found() => continue,
+                Err(e) => return Err(e),
+            };
+            let entry = ranking
+                .items
+                .into_iter()
+                .find(|entry| entry.player_id == player.player_id)
+                .unwrap_or_else(|| GlobalRanking {
+                    player_id: player.player_id.clone(),
+                    nickname: player.nickname.clone(),
+                    position: ranking.position,
+                    faceit_elo: detail.faceit_elo.unwrap_or_default(),
+                    game_skill_level: detail.skill_level.unwrap_or_default(),
+                    country: player.country.clone(),
+                });
+            if best
+                .as_ref()
+                .is_none_or(|best| entry.position < best.position)
+            {
+                best = Some(entry);
+            }
+        }
+
+        Ok(best)
+    }
+
+    /// Get the player's statistics for a single map (or other segment label)
+    ///
+    /// Fetches the player's game statistics and returns the matching segment,
+    /// or `None` if the player has no stats for that label.
+    ///
+    /// # Arguments
+    /// * `game_id` - The game ID (e.g., "cs2", "csgo")
+    /// * `label` - The segment label (e.g., "de_mirage")
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// if let Some(mirage) = player.map_stats("cs2", "de_mirage").await? {
+    ///     println!("Mirage stats: {:?}", mirage.stats);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn map_stats(
+        &self,
+        game_id: &str,
+        label: &str,
+    ) -> Result<Option<StatsSegment>, Error> {
+        let stats = self.stats(game_id).await?;
+        Ok(stats.segment(label).cloned())
+    }
+
     /// Get the player's match history
     ///
     /// # Arguments
@@ -129,6 +238,111 @@ impl<'a> Player<'a> {
             .await
     }
 
+    /// Get the player's match history using a [`PlayerHistoryParams`] struct
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::{ergonomic::Player, PlayerHistoryParams}};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// let history = player.history_with(&PlayerHistoryParams::new("cs2").limit(50)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn history_with(
+        &self,
+        params: &PlayerHistoryParams,
+    ) -> Result<MatchHistoryList, Error> {
+        self.client
+            .get_player_history_with(&self.player_id, params)
+            .await
+    }
+
+    /// Get the player's entire match history within a time window
+    ///
+    /// See [`Client::get_player_history_all`]; each match is returned once.
+    ///
+    /// # Arguments
+    /// * `game` - The game ID (required)
+    /// * `from` - Optional start timestamp (Unix time)
+    /// * `to` - Optional end timestamp (Unix time)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// let matches = player.history_all("cs2", Some(1_700_000_000), None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn history_all(
+        &self,
+        game: &str,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<MatchHistory>, Error> {
+        self.client
+            .get_player_history_all(&self.player_id, game, from, to)
+            .await
+    }
+
+    /// Get the player's most recent matches
+    ///
+    /// # Arguments
+    /// * `game` - The game ID (required)
+    /// * `count` - The number of matches to collect
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// let matches = player.recent_matches("cs2", 30).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recent_matches(&self, game: &str, count: u32) -> Result<Vec<MatchHistory>, Error> {
+        self.client
+            .get_recent_matches(&self.player_id, game, count)
+            .await
+    }
+
+    /// Get an approximate ELO progression over the player's recent matches
+    ///
+    /// See [`Client::get_elo_history`] for how the values are derived.
+    ///
+    /// # Arguments
+    /// * `game` - The game ID (e.g., "cs2")
+    /// * `count` - The number of recent matches to look at
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// let elo = player.elo_history("cs2", 50).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn elo_history(
+        &self,
+        game: &str,
+        count: u32,
+    ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, i64)>, Error> {
+        self.client
+            .get_elo_history(&self.player_id, game, count)
+            .await
+    }
+
     /// Get the player's bans
     ///
     /// # Arguments
@@ -156,6 +370,50 @@ impl<'a> Player<'a> {
             .await
     }
 
+    /// Get the player's bans that are currently in effect
+    ///
+    /// See [`Client::get_active_player_bans`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// let bans = player.active_bans().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn active_bans(&self) -> Result<Vec<PlayerBan>, Error> {
+        self.client.get_active_player_bans(&self.player_id).await
+    }
+
+    /// Check whether the player is currently banned
+    ///
+    /// # Arguments
+    /// * `game` - Only consider bans for this game ID (e.g., "cs2"), or any game if `None`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// if player.is_banned(Some("cs2")).await? {
+    ///     println!("Player is banned from CS2");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_banned(&self, game: Option<&str>) -> Result<bool, Error> {
+        let bans = self.active_bans().await?;
+        Ok(bans
+            .iter()
+            .any(|ban| game.is_none_or(|game| ban.game.eq_ignore_ascii_case(game))))
+    }
+
     /// Get the player's hubs
     ///
     /// # Arguments
@@ -202,6 +460,38 @@ impl<'a> Player<'a> {
             .await
     }
 
+    /// Get the player's teams for one game
+    ///
+    /// The teams endpoint has no game filter, so the page selected by
+    /// `offset` and `limit` is filtered client-side (see
+    /// [`TeamList::filter_by_game`]) and may hold fewer than `limit` teams.
+    ///
+    /// # Arguments
+    /// * `game` - The game ID (e.g., "cs2")
+    /// * `offset` - Optional offset for pagination (default: 0)
+    /// * `limit` - Optional limit for pagination (default: 20, max: 100)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::ergonomic::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let player = Player::new("player-id-here", &client);
+    /// let cs2_teams = player.teams_for_game("cs2", Some(0), Some(100)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn teams_for_game(
+        &self,
+        game: &str,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Team>, Error> {
+        let teams = self.teams(offset, limit).await?;
+        Ok(teams.filter_by_game(game).into_iter().cloned().collect())
+    }
+
     /// Get the player's tournaments
     ///
     /// # Arguments
@@ -0,0 +1,60 @@
+//! Stored responses for conditional requests
+//!
+//! Enabled with [`ClientBuilder::conditional_requests`](super::ClientBuilder::conditional_requests).
+
+use std::collections::{HashMap, VecDeque};
+
+/// Maximum number of responses kept for revalidation
+pub(crate) const MAX_STORED_RESPONSES: usize = 1024;
+
+/// `ETag` and body of the last successful response per URL
+///
+/// Holds at most `capacity` responses; once full, the response stored
+/// longest ago is evicted.
+#[derive(Debug)]
+pub(crate) struct EtagStore {
+    capacity: usize,
+    entries: HashMap<String, (String, String)>,
+    /// URLs in the order their responses were stored, oldest first
+    order: VecDeque<String>,
+}
+
+impl EtagStore {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Get the stored `(etag, body)` for a URL
+    pub(crate) fn get(&self, url: &str) -> Option<&(String, String)> {
+        self.entries.get(url)
+    }
+
+    /// Store the response for a URL, evicting the oldest one if full
+    pub(crate) fn insert(&mut self, url: &str, etag: String, body: String) {
+        if self.entries.insert(url.to_string(), (etag, body)).is_some() {
+            self.order.retain(|stored| stored != url);
+        }
+        self.order.push_back(url.to_string());
+
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    pub(crate) fn remove(&mut self, url: &str) {
+        if self.entries.remove(url).is_some() {
+            self.order.retain(|stored| stored != url);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
@@ -0,0 +1,46 @@
+//! Maximum page sizes accepted by the Data API
+//!
+//! The paginated endpoints default to small pages (usually 20 items) but
+//! accept larger ones up to a per-endpoint maximum. Helpers that collect a
+//! whole collection, such as [`Client::get_all_hub_members`], always request
+//! the maximum for their endpoint to keep the number of requests down. Use
+//! these constants to do the same when paging by hand.
+//!
+//! [`Client::get_all_hub_members`]: super::Client::get_all_hub_members
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use faceit::HttpClient;
+//! use faceit::http::limits::MAX_PAGE_LIMIT;
+//!
+//! # async fn example() -> Result<(), faceit::error::Error> {
+//! let client = HttpClient::new();
+//! let bans = client
+//!     .get_player_bans("player-id", Some(0), Some(MAX_PAGE_LIMIT))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+/// Maximum page size accepted by most paginated endpoints
+///
+/// Used for player bans, player match history, games, hub matches and
+/// championship matches and listings.
+pub const MAX_PAGE_LIMIT: i64 = 100;
+
+/// Maximum page size accepted by the hub list endpoints
+///
+/// Used for hub members and a player's or organizer's hubs.
+pub const MAX_HUB_PAGE_LIMIT: i64 = 50;
+
+/// Maximum offset accepted by the hub list endpoints
+///
+/// Together with [`MAX_HUB_PAGE_LIMIT`], at most 1050 items can be reached.
+pub const MAX_HUB_OFFSET: i64 = 1000;
+
+/// Maximum page size accepted by the hub roles endpoint
+pub const MAX_HUB_ROLES_PAGE_LIMIT: i64 = 50;
+
+/// Maximum page size accepted by the championship subscriptions endpoint
+pub const MAX_SUBSCRIPTIONS_PAGE_LIMIT: i64 = 10;
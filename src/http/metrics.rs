@@ -0,0 +1,60 @@
+//! Request metrics hooks
+//!
+//! Implement [`Metrics`] to feed request counts and latencies into a metrics
+//! backend such as Prometheus, and install it with
+//! [`ClientBuilder::metrics`](super::ClientBuilder::metrics).
+
+use std::time::Duration;
+
+/// Callbacks invoked by the client around every API request
+///
+/// Both methods have empty default implementations, so implementors only
+/// need to override the ones they care about. `path` is the URL path exactly
+/// as sent, without the query string, so it contains the real IDs (e.g.
+/// `/data/v4/players/0f1a...`).
+///
+/// Because of this, `path` is unbounded: using it as a metrics label creates
+/// one time series per player, match or hub requested. Label by a bounded
+/// value instead, such as the status code, or map the path to a route
+/// template yourself before using it as a label.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::Metrics;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::time::Duration;
+///
+/// #[derive(Default)]
+/// struct RequestCounter {
+///     requests: AtomicU64,
+///     failures: AtomicU64,
+/// }
+///
+/// impl Metrics for RequestCounter {
+///     fn on_request_start(&self, _path: &str) {
+///         self.requests.fetch_add(1, Ordering::Relaxed);
+///     }
+///
+///     fn on_request_end(&self, _path: &str, status: Option<u16>, _elapsed: Duration) {
+///         if !matches!(status, Some(200..=299)) {
+///             self.failures.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait Metrics: Send + Sync {
+    /// Called before a request is sent
+    fn on_request_start(&self, path: &str) {
+        let _ = path;
+    }
+
+    /// Called once a request has completed
+    ///
+    /// `status` is the HTTP status code, or `None` if no response was received
+    /// (e.g. on timeouts or connection failures). `elapsed` covers sending the
+    /// request and reading the full response body.
+    fn on_request_end(&self, path: &str, status: Option<u16>, elapsed: Duration) {
+        let _ = (path, status, elapsed);
+    }
+}
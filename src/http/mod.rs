@@ -1,6 +1,21 @@
+pub mod circuit;
 pub mod client;
+#[cfg(feature = "coalesce")]
+mod coalesce;
+mod concurrency;
+mod etags;
+pub mod limits;
+pub mod metrics;
+pub mod params;
+mod resolve;
 
+pub use circuit::{CircuitBreaker, CircuitState};
 pub use client::{Client, ClientBuilder};
+pub use metrics::Metrics;
+pub use params::{
+    GlobalRankingParams, MatchType, PlayerHistoryParams, PlayerRef, SearchHubsParams,
+    SearchPlayersParams, SearchTeamsParams,
+};
 
 #[cfg(feature = "ergonomic")]
 pub mod ergonomic;
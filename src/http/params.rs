@@ -0,0 +1,447 @@
+//! Parameter structs for endpoints with many optional arguments
+//!
+//! These are accepted by the `*_with` variants of the [`Client`](super::Client)
+//! methods and avoid mixing up positional `Option` arguments. Each struct can
+//! be built with its builder methods, or with struct literal syntax since all
+//! fields are public and unset filters default to `None`. Required fields left
+//! empty, e.g. by `..Default::default()`, are rejected with
+//! [`Error::MissingParameter`] before a request is sent.
+
+use crate::error::Error;
+
+/// Any identifier that can be used to look up a player
+///
+/// Accepted by [`Client::player`](super::Client::player), which dispatches to
+/// the matching endpoint.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::PlayerRef;
+///
+/// let by_id = PlayerRef::Id("5ea07280-2399-4c7e-88ab-f2f7db0c449f".to_string());
+/// let by_steam = PlayerRef::GamePlayerId {
+///     id: "76561198034202275".to_string(),
+///     game: "cs2".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlayerRef {
+    /// FACEIT player ID
+    Id(String),
+    /// FACEIT nickname
+    Nickname(String),
+    /// Game-specific player ID (e.g. a Steam ID) for the given game
+    GamePlayerId { id: String, game: String },
+}
+
+/// Match list filter for hub and championship matches
+///
+/// Accepted by [`Client::get_hub_matches`](super::Client::get_hub_matches) and
+/// [`Client::get_championship_matches`](super::Client::get_championship_matches).
+/// Use [`str::parse`] or the `*_str` variants of those methods to pass
+/// user-provided strings; unknown values are rejected instead of being sent to
+/// the API, which would ignore them.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::MatchType;
+///
+/// assert_eq!("past".parse::<MatchType>().unwrap(), MatchType::Past);
+/// assert!("live".parse::<MatchType>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MatchType {
+    /// All matches
+    #[default]
+    All,
+    /// Matches that have not started yet
+    Upcoming,
+    /// Matches currently being played
+    Ongoing,
+    /// Finished matches
+    Past,
+}
+
+impl MatchType {
+    /// Get the value sent as the `type` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchType::All => "all",
+            MatchType::Upcoming => "upcoming",
+            MatchType::Ongoing => "ongoing",
+            MatchType::Past => "past",
+        }
+    }
+}
+
+impl std::fmt::Display for MatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for MatchType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            MatchType::All,
+            MatchType::Upcoming,
+            MatchType::Ongoing,
+            MatchType::Past,
+        ]
+        .into_iter()
+        .find(|t| t.as_str().eq_ignore_ascii_case(s))
+        .ok_or_else(|| {
+            Error::InvalidParameter(format!(
+                "match type must be one of all, upcoming, ongoing, past (got {:?})",
+                s
+            ))
+        })
+    }
+}
+
+/// Parameters for [`Client::get_player_history_with`](super::Client::get_player_history_with)
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::PlayerHistoryParams;
+///
+/// let params = PlayerHistoryParams::new("cs2").from(1_700_000_000).limit(50);
+///
+/// let literal = PlayerHistoryParams {
+///     game: "cs2".into(),
+///     from: Some(1_700_000_000),
+///     limit: Some(50),
+///     ..Default::default()
+/// };
+/// assert_eq!(params, literal);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PlayerHistoryParams {
+    /// Game ID (e.g. "cs2")
+    pub game: String,
+    /// Only include matches started at or after this timestamp (Unix time)
+    pub from: Option<i64>,
+    /// Only include matches started before this timestamp (Unix time)
+    pub to: Option<i64>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+    /// Limit for pagination (default: 20, max: 100)
+    pub limit: Option<i64>,
+}
+
+impl PlayerHistoryParams {
+    /// Create parameters for the given game ID
+    pub fn new(game: impl Into<String>) -> Self {
+        Self {
+            game: game.into(),
+            from: None,
+            to: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Only include matches started at or after this timestamp (Unix time)
+    pub fn from(mut self, from: i64) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Only include matches started before this timestamp (Unix time)
+    pub fn to(mut self, to: i64) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Set the offset for pagination (default: 0)
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination (default: 20, max: 100)
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check that the required fields are set
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        require("game", &self.game)?;
+        Ok(())
+    }
+}
+
+/// Parameters for [`Client::search_players_with`](super::Client::search_players_with)
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::SearchPlayersParams;
+///
+/// let params = SearchPlayersParams::new("s1mple").game("cs2").country("UA");
+///
+/// // Equivalent, using struct update syntax
+/// let literal = SearchPlayersParams {
+///     nickname: "s1mple".into(),
+///     game: Some("cs2".into()),
+///     country: Some("UA".into()),
+///     ..Default::default()
+/// };
+/// assert_eq!(params, literal);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchPlayersParams {
+    /// Nickname to search for
+    pub nickname: String,
+    /// Only include players of this game ID
+    pub game: Option<String>,
+    /// Only include players from this country (ISO 3166-1)
+    pub country: Option<String>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+    /// Limit for pagination (default: 20, max: 100)
+    pub limit: Option<i64>,
+}
+
+impl SearchPlayersParams {
+    /// Create parameters searching for the given nickname
+    pub fn new(nickname: impl Into<String>) -> Self {
+        Self {
+            nickname: nickname.into(),
+            game: None,
+            country: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Only include players of this game ID
+    pub fn game(mut self, game: impl Into<String>) -> Self {
+        self.game = Some(game.into());
+        self
+    }
+
+    /// Only include players from this country (ISO 3166-1)
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the offset for pagination (default: 0)
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination (default: 20, max: 100)
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check that the required fields are set
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        require("nickname", &self.nickname)?;
+        Ok(())
+    }
+}
+
+/// Parameters for [`Client::search_teams_with`](super::Client::search_teams_with)
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::SearchTeamsParams;
+///
+/// let params = SearchTeamsParams::new("navi").game("cs2").limit(10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchTeamsParams {
+    /// Team nickname to search for
+    pub nickname: String,
+    /// Only include teams of this game ID
+    pub game: Option<String>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+    /// Limit for pagination (default: 20, max: 100)
+    pub limit: Option<i64>,
+}
+
+impl SearchTeamsParams {
+    /// Create parameters searching for the given team nickname
+    pub fn new(nickname: impl Into<String>) -> Self {
+        Self {
+            nickname: nickname.into(),
+            game: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Only include teams of this game ID
+    pub fn game(mut self, game: impl Into<String>) -> Self {
+        self.game = Some(game.into());
+        self
+    }
+
+    /// Set the offset for pagination (default: 0)
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination (default: 20, max: 100)
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check that the required fields are set
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        require("nickname", &self.nickname)?;
+        Ok(())
+    }
+}
+
+/// Parameters for [`Client::search_hubs_with`](super::Client::search_hubs_with)
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::SearchHubsParams;
+///
+/// let params = SearchHubsParams::new("weekly").game("cs2").region("EU");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchHubsParams {
+    /// Hub name to search for
+    pub name: String,
+    /// Only include hubs of this game ID
+    pub game: Option<String>,
+    /// Only include hubs in this region
+    pub region: Option<String>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+    /// Limit for pagination (default: 20, max: 100)
+    pub limit: Option<i64>,
+}
+
+impl SearchHubsParams {
+    /// Create parameters searching for the given hub name
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            game: None,
+            region: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Only include hubs of this game ID
+    pub fn game(mut self, game: impl Into<String>) -> Self {
+        self.game = Some(game.into());
+        self
+    }
+
+    /// Only include hubs in this region
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Set the offset for pagination (default: 0)
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination (default: 20, max: 100)
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check that the required fields are set
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        require("name", &self.name)?;
+        Ok(())
+    }
+}
+
+/// Parameters for [`Client::get_global_ranking_with`](super::Client::get_global_ranking_with)
+///
+/// # Examples
+///
+/// ```
+/// use faceit::http::GlobalRankingParams;
+///
+/// let params = GlobalRankingParams::new("cs2", "EU").country("DE").limit(50);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GlobalRankingParams {
+    /// Game ID (e.g. "cs2")
+    pub game_id: String,
+    /// Region (e.g. "EU")
+    pub region: String,
+    /// Only include players from this country (ISO 3166-1)
+    pub country: Option<String>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+    /// Limit for pagination (default: 20, max: 100)
+    pub limit: Option<i64>,
+}
+
+impl GlobalRankingParams {
+    /// Create parameters for the given game ID and region
+    pub fn new(game_id: impl Into<String>, region: impl Into<String>) -> Self {
+        Self {
+            game_id: game_id.into(),
+            region: region.into(),
+            country: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Only include players from this country (ISO 3166-1)
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the offset for pagination (default: 0)
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination (default: 20, max: 100)
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check that the required fields are set
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        require("game_id", &self.game_id)?;
+        require("region", &self.region)?;
+        Ok(())
+    }
+}
+
+/// Reject a required parameter left empty, which would otherwise be sent as
+/// an empty path segment or query value
+fn require(name: &str, value: &str) -> Result<(), Error> {
+    if value.trim().is_empty() {
+        Err(Error::MissingParameter(name.to_string()))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! Resolve summary types to their full objects
+//!
+//! List endpoints return trimmed-down summaries (e.g. [`TournamentSimple`]).
+//! The methods here fetch the corresponding detail object using the ID the
+//! summary already carries.
+
+use super::Client;
+use crate::error::Error;
+use crate::types::*;
+use futures_util::{StreamExt, TryStreamExt};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+
+/// Number of requests in flight when resolving several IDs
+pub(crate) const RESOLVE_CONCURRENCY: usize = 5;
+
+/// Fetch the resource for each of `ids`, [`RESOLVE_CONCURRENCY`] at a time
+///
+/// Resources are returned in the order of `ids`. Those that do not exist
+/// (`404 Not Found`) are skipped; any other error is returned, so throttling
+/// or a bad API key is not mistaken for unknown IDs.
+pub(crate) async fn resolve_ordered<I, F, Fut, T>(ids: I, fetch: F) -> Result<Vec<T>, Error>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    futures_util::stream::iter(ids)
+        .map(fetch)
+        .map(|fetched| async move {
+            match fetched.await {
+                Ok(resource) => Ok(Some(resource)),
+                Err(e) if e.is_not_found() => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+        .buffered(RESOLVE_CONCURRENCY)
+        .try_filter_map(|resource| async move { Ok(resource) })
+        .try_collect()
+        .await
+}
+
+/// Like [`resolve_ordered`], but request each distinct ID once and key the
+/// resources by ID
+pub(crate) async fn resolve_keyed<I, F, Fut, T>(
+    ids: I,
+    mut fetch: F,
+) -> Result<HashMap<String, T>, Error>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut seen = HashSet::new();
+    let ids = ids
+        .into_iter()
+        .map(|id| id.as_ref().to_string())
+        .filter(|id| seen.insert(id.clone()));
+
+    let resources = resolve_ordered(ids, |id: String| {
+        let fetched = fetch(id.clone());
+        async move { Ok((id, fetched.await?)) }
+    })
+    .await?;
+
+    Ok(resources.into_iter().collect())
+}
+
+impl TournamentSimple {
+    /// Fetch the full [`Tournament`] this summary refers to
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Client::get_tournament`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let tournaments = client.get_player_tournaments("player-id", None, None).await?;
+    /// for summary in &tournaments {
+    ///     let tournament = summary.fetch_full(&client).await?;
+    ///     println!("{}: {:?}", tournament.name, tournament.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_full(&self, client: &Client) -> Result<Tournament, Error> {
+        client.get_tournament(&self.tournament_id, None).await
+    }
+}
+
+impl MatchHistory {
+    /// Fetch the full [`Match`] for this history entry
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Client::get_match`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let history = client.get_player_history("player-id", "cs2", None, None, Some(0), Some(1)).await?;
+    /// if let Some(entry) = history.items.first() {
+    ///     let match_data = entry.fetch_match(&client).await?;
+    ///     println!("{}", match_data);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_match(&self, client: &Client) -> Result<Match, Error> {
+        client.get_match(&self.match_id).await
+    }
+}
+
+impl CompetitionSearch {
+    /// Fetch the full [`Championship`] for this search result
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the result is not a championship
+    /// (e.g. a hub), without sending a request. Otherwise returns the same
+    /// errors as [`Client::get_championship`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, types::CompetitionSearch};
+    /// # async fn example(result: &CompetitionSearch) -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let championship = result.fetch_championship(&client).await?;
+    /// println!("{}", championship.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_championship(&self, client: &Client) -> Result<Championship, Error> {
+        if self.competition_type_kind() != CompetitionType::Championship {
+            return Err(Error::InvalidParameter(format!(
+                "competition {} is a {}, not a championship",
+                self.competition_id, self.competition_type
+            )));
+        }
+        client.get_championship(&self.competition_id, None).await
+    }
+}
+
+impl Player {
+    /// Fetch the full profiles of several players, each at most once
+    ///
+    /// Duplicate IDs are requested only once. The result is keyed by player
+    /// ID; players that do not exist (`404 Not Found`) are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error other than a `404 Not Found`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, types::Player};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let match_data = client.get_match("match-id").await?;
+    /// let ids = match_data.roster().into_iter().map(|member| member.player_id.as_str());
+    /// let players = Player::resolve_many(&client, ids).await?;
+    /// for (id, player) in &players {
+    ///     println!("{}: {}", id, player.nickname);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_many<I>(
+        client: &Client,
+        player_ids: I,
+    ) -> Result<HashMap<String, Player>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        resolve_keyed(player_ids, |id| async move { client.get_player(&id).await }).await
+    }
+}
+
+impl UsersSearchList {
+    /// Fetch the full [`Player`] profile of every search result
+    ///
+    /// Players are returned in search result order. Results whose profile no
+    /// longer exists (`404 Not Found`) are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error other than a `404 Not Found`, so a throttled or
+    /// failing burst is not mistaken for fewer results.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let results = client.search_players("s1mple", None, None, None, None).await?;
+    /// for player in results.hydrate(&client).await? {
+    ///     println!("{}: {:?}", player.nickname, player.steam_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn hydrate(&self, client: &Client) -> Result<Vec<Player>, Error> {
+        resolve_ordered(&self.items, |user| client.get_player(&user.player_id)).await
+    }
+}
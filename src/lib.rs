@@ -8,7 +8,7 @@
 //! ## Features
 //!
 //! - **Type-safe API**: All API responses are deserialized into strongly-typed Rust structs
-//! - **Async/await support**: Built on `tokio` and `reqwest` for async operations
+//! - **Async/await support**: Built on `reqwest` for async operations
 //! - **Builder pattern**: Flexible client configuration via [`HttpClientBuilder`]
 //! - **Comprehensive error handling**: Detailed error types for all failure modes
 //! - **Ergonomic APIs**: Optional wrapper types for convenient resource access (enable `ergonomic` feature)
@@ -46,16 +46,63 @@
 //! # }
 //! ```
 //!
+//! ## Async Runtimes
+//!
+//! Apart from the optional `tokio` feature, which provides the timer used by
+//! polling helpers, the crate does not depend on `tokio` directly. `reqwest`'s HTTP stack
+//! requires a Tokio reactor to drive connections. Under other runtimes, either
+//! wrap the client's futures with a compatibility layer such as `async-compat`,
+//! or perform the HTTP requests yourself and decode the response bodies with
+//! [`types::parse_response`].
+//!
+//! ## Cancellation
+//!
+//! Every request is a plain future driven by the caller; the client never
+//! spawns background tasks. Dropping a future returned by a client method
+//! cancels it, closing the in-flight HTTP request, so requests can be bound
+//! to a shutdown signal with `tokio::select!` (e.g. on a `CancellationToken`)
+//! or a timeout. This also holds for helpers that send several requests and
+//! for coalesced requests, which are only cancelled once every caller
+//! waiting on them has been dropped.
+//!
+//! ## TLS Backends
+//!
+//! The TLS backend is selected with one of two mutually exclusive features:
+//!
+//! - `rustls-tls` (default) - pure Rust TLS, no system OpenSSL required
+//! - `native-tls` - the platform's native TLS library
+//!
+//! To switch, disable default features and enable `native-tls` instead.
+//!
+//! ## Types Without the Client
+//!
+//! The response types do not depend on the HTTP stack. To deserialize FACEIT
+//! JSON received from elsewhere without pulling in `reqwest`, depend on the
+//! crate with `default-features = false, features = ["types"]` and use
+//! [`types::parse_response`].
+//!
 //! See the [README](https://github.com/muijf/faceit) for more examples and documentation.
 //!
 //! ## Modules
 //!
 //! - [`error`] - Error types for API operations
-//! - [`http`] - HTTP client and builder types
-//! - [`types`] - API response types
+//! - [`http`] - HTTP client and builder types (enable `http` feature, default)
+//! - [`types`] - API response types (enable `types` feature, default)
+//! - [`urls`] - Links to the FACEIT website
+//! - `webhook` - Webhook event types and signature verification (enable `webhook` feature)
 
+#[cfg(feature = "csv")]
+mod csv_export;
 pub mod error;
+#[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "ndjson")]
+mod ndjson_export;
+#[cfg(feature = "types")]
 pub mod types;
+pub mod urls;
+#[cfg(feature = "webhook")]
+pub mod webhook;
 
+#[cfg(feature = "http")]
 pub use http::{Client as HttpClient, ClientBuilder as HttpClientBuilder};
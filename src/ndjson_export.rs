@@ -0,0 +1,99 @@
+//! Newline-delimited JSON export
+//!
+//! Each item is written as one compact JSON object followed by `\n`, to any
+//! Tokio [`AsyncWrite`]. Items are serialized one at a time, so exporting a
+//! stream keeps memory use flat.
+
+use crate::error::Error;
+use crate::types::*;
+use futures_util::{Stream, TryStreamExt};
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Write `item` as a single JSON line
+async fn write_line<W, T>(writer: &mut W, item: &T) -> Result<(), Error>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let mut line = serde_json::to_vec(item)?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+    Ok(())
+}
+
+/// Write every item of `items` as one JSON line, returning the number written
+///
+/// Stops at the first error from the stream or the writer. The writer is
+/// flushed once the stream ends.
+pub(crate) async fn write_stream<W, T>(
+    writer: &mut W,
+    items: impl Stream<Item = Result<T, Error>>,
+) -> Result<u64, Error>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let mut items = std::pin::pin!(items);
+    let mut written = 0;
+    while let Some(item) = items.try_next().await? {
+        write_line(writer, &item).await?;
+        written += 1;
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+
+impl MatchHistoryList {
+    /// Write the matches as newline-delimited JSON, one match per line
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let history = client.get_player_history("player-id", "cs2", None, None, Some(0), Some(100)).await?;
+    /// let mut file = tokio::fs::File::create("history.ndjson").await?;
+    /// history.write_ndjson(&mut file).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_ndjson<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<(), Error> {
+        write_stream(
+            writer,
+            futures_util::stream::iter(self.items.iter().map(Ok)),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_match_history_write_ndjson() {
+        let history: MatchHistoryList = parse_response(
+            r#"{"start": 0, "end": 2, "items": [
+                {"match_id": "m-1", "game_id": "cs2", "status": "FINISHED"},
+                {"match_id": "m-2", "game_id": "cs2", "status": "FINISHED"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let mut out = Vec::new();
+        history.write_ndjson(&mut out).await.unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(out.ends_with('\n'));
+        let second: MatchHistory = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(second.match_id, "m-2");
+    }
+}
@@ -1,6 +1,374 @@
+use crate::error::Error;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+pub mod raw;
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Parse a raw API response body into one of the response types
+///
+/// This is the same deserialization step the client applies to successful
+/// responses, so it can be used to validate saved fixtures or replay recorded
+/// responses without making a network call.
+///
+/// # Errors
+///
+/// Returns [`Error::Json`] if the body cannot be parsed into `T`.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::types::{parse_response, Pagination};
+///
+/// let pagination: Pagination = parse_response(r#"{"start": 0, "end": 20}"#)?;
+/// assert_eq!(pagination.end, 20);
+/// # Ok::<(), faceit::error::Error>(())
+/// ```
+pub fn parse_response<T>(body: &str) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_str(body).map_err(Error::Json)
+}
+
+/// Number that some endpoints return as a JSON string (e.g. `"10"` instead of `10`)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber<T> {
+    Number(T),
+    String(String),
+}
+
+impl<T> StringOrNumber<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn into_number<E: serde::de::Error>(self) -> Result<T, E> {
+        match self {
+            StringOrNumber::Number(n) => Ok(n),
+            StringOrNumber::String(s) => s.trim().parse().map_err(E::custom),
+        }
+    }
+}
+
+/// Deserialize a number that may be sent as a number or a numeric string
+///
+/// Use with `#[serde(deserialize_with = "string_or_number")]` on fields whose
+/// type differs between endpoints.
+fn string_or_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de> + std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    StringOrNumber::deserialize(deserializer)?.into_number()
+}
+
+/// Optional variant of [`string_or_number`]; pair it with `#[serde(default)]`
+fn option_string_or_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de> + std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    Option::<StringOrNumber<T>>::deserialize(deserializer)?
+        .map(StringOrNumber::into_number)
+        .transpose()
+}
+
+/// Deserialize an optional string, treating an empty string as `None`
+///
+/// The API sends `""` instead of omitting image URLs such as `avatar` or
+/// `cover_image` when none is set. Pair it with `#[serde(default)]`.
+fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|s| !s.is_empty()))
+}
+
+// ============================================================================
+// Enum Helpers
+// ============================================================================
+
+/// Define an enum over string values returned by the API
+///
+/// Each variant maps to its API value (plus optional aliases accepted when
+/// parsing). Parsing is case-insensitive. Values not known to this version of
+/// the crate are preserved in an `Unknown` variant so that new API values never
+/// fail deserialization.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal $(| $alias:literal)*
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+            /// A value not known to this version of the crate
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Get the value as used by the API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                $(
+                    if value.eq_ignore_ascii_case($value)
+                        $(|| value.eq_ignore_ascii_case($alias))*
+                    {
+                        return Self::$variant;
+                    }
+                )+
+                Self::Unknown(value.to_string())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+/// Implement collection helpers over the `items` of paginated list responses
+///
+/// Adds `iter()`, `len()`, `is_empty()` and `IntoIterator` for both the list
+/// and a reference to it, so `for item in &list` and `for item in list` work
+/// directly.
+macro_rules! list_type {
+    ($($name:ident => $item:ty),+ $(,)?) => {
+        $(
+            impl $name {
+                /// Iterate over the items in this page
+                pub fn iter(&self) -> std::slice::Iter<'_, $item> {
+                    self.items.iter()
+                }
+
+                /// Get the number of items in this page
+                pub fn len(&self) -> usize {
+                    self.items.len()
+                }
+
+                /// Whether this page has no items
+                pub fn is_empty(&self) -> bool {
+                    self.items.is_empty()
+                }
+            }
+
+            impl IntoIterator for $name {
+                type Item = $item;
+                type IntoIter = std::vec::IntoIter<$item>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.items.into_iter()
+                }
+            }
+
+            impl<'a> IntoIterator for &'a $name {
+                type Item = &'a $item;
+                type IntoIter = std::slice::Iter<'a, $item>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.items.iter()
+                }
+            }
+        )+
+    };
+}
+
+/// Implement `PartialEq`, `Eq` and `Hash` for resource types by their ID field
+///
+/// This makes resources usable in sets and as map keys. Equality is identity
+/// based: other fields are not compared, so two snapshots of the same resource
+/// taken at different times are equal.
+macro_rules! identity_eq {
+    ($($name:ident => $id:ident),+ $(,)?) => {
+        $(
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.$id == other.$id
+                }
+            }
+
+            impl Eq for $name {}
+
+            impl std::hash::Hash for $name {
+                fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                    self.$id.hash(state);
+                }
+            }
+        )+
+    };
+}
+
+identity_eq! {
+    Player => player_id,
+    Match => match_id,
+    Team => team_id,
+    Hub => hub_id,
+    Championship => championship_id,
+    Game => game_id,
+}
+
+/// Add an `extra` accessor for fields captured by `#[serde(flatten)] extra`
+///
+/// Unknown fields returned by the API are kept rather than dropped, so new
+/// fields can be read before this crate models them.
+macro_rules! extra_fields {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            impl $name {
+                /// Get a field returned by the API that this crate does not model
+                pub fn extra(&self, key: &str) -> Option<&serde_json::Value> {
+                    self.extra.get(key)
+                }
+            }
+        )+
+    };
+}
+
+extra_fields! {
+    GameDetail,
+    Match,
+    PlayerStats,
+    PlayerStatsSimple,
+    RoundStats,
+    StatsCompetitionPlayer,
+    StatsSegment,
+    TeamStats,
+    TeamStatsSimple,
+    Tournament,
+    Player,
+    Game,
+    Hub,
+    Championship,
+    Team,
+    Leaderboard,
+}
+
+/// Fall back to a deprecated ID field when the current one is missing
+///
+/// FACEIT renamed some ID fields but still sends the old name alongside the
+/// new one, so a plain `#[serde(alias)]` would fail with a duplicate field on
+/// current responses. Types listed here derive with `#[serde(remote = "Self")]`
+/// and deserialize through a [`serde_json::Value`], copying the old field into
+/// the new one only when the new one is absent (e.g. in archived JSON).
+macro_rules! legacy_id {
+    ($($name:ident => $id:ident <- $legacy:ident),+ $(,)?) => {
+        $(
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let mut value = serde_json::Value::deserialize(deserializer)?;
+                    if let Some(object) = value.as_object_mut()
+                        && !object.contains_key(stringify!($id))
+                        && let Some(legacy) = object.get(stringify!($legacy)).cloned()
+                    {
+                        object.insert(stringify!($id).to_string(), legacy);
+                    }
+                    $name::deserialize(value).map_err(serde::de::Error::custom)
+                }
+            }
+
+            impl Serialize for $name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    $name::serialize(self, serializer)
+                }
+            }
+        )+
+    };
+}
+
+legacy_id! {
+    Championship => championship_id <- id,
+    Tournament => tournament_id <- competition_id,
+}
+
+// ============================================================================
+// Country Codes
+// ============================================================================
+
+/// ISO 3166-1 alpha-2 country codes, sorted
+const ISO_3166_ALPHA_2: [&str; 249] = [
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// Check whether `code` is an ISO 3166-1 alpha-2 country code
+///
+/// The comparison is case-insensitive, as the API returns lowercase codes
+/// (e.g. `"de"`) while accepting uppercase ones as filters.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::types::is_valid_country_code;
+///
+/// assert!(is_valid_country_code("GB"));
+/// assert!(is_valid_country_code("de"));
+/// assert!(!is_valid_country_code("UK"));
+/// ```
+pub fn is_valid_country_code(code: &str) -> bool {
+    code.len() == 2
+        && ISO_3166_ALPHA_2
+            .binary_search(&code.to_ascii_uppercase().as_str())
+            .is_ok()
+}
+
 // ============================================================================
 // Pagination Types
 // ============================================================================
@@ -16,62 +384,205 @@ pub struct Pagination {
 // Player Types
 // ============================================================================
 
+string_enum! {
+    /// FACEIT membership tier
+    ///
+    /// Players report these in `memberships` (a list) while match rosters use
+    /// a single `membership` string; both parse into this enum.
+    pub enum Membership {
+        Free => "free",
+        Premium => "premium",
+        Plus => "plus",
+        Unlimited => "unlimited",
+    }
+}
+
+impl Membership {
+    /// Whether this is a paid tier
+    pub fn is_premium(&self) -> bool {
+        matches!(
+            self,
+            Membership::Premium | Membership::Plus | Membership::Unlimited
+        )
+    }
+}
+
 /// Player information from FACEIT API
+///
+/// Compared and hashed by `player_id` only.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     #[serde(rename = "player_id")]
     pub player_id: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "steam_id_64")]
+    #[serde(rename = "steam_id_64", skip_serializing_if = "Option::is_none")]
     pub steam_id_64: Option<String>,
-    #[serde(rename = "steam_nickname")]
+    #[serde(rename = "steam_nickname", skip_serializing_if = "Option::is_none")]
     pub steam_nickname: Option<String>,
-    #[serde(rename = "new_steam_id")]
+    #[serde(rename = "new_steam_id", skip_serializing_if = "Option::is_none")]
     pub new_steam_id: Option<String>,
-    #[serde(rename = "memberships")]
+    #[serde(rename = "memberships", skip_serializing_if = "Option::is_none")]
     pub memberships: Option<Vec<String>>,
-    #[serde(rename = "games")]
+    #[serde(rename = "games", skip_serializing_if = "Option::is_none")]
     pub games: Option<std::collections::HashMap<String, GameDetail>>,
-    #[serde(rename = "verified")]
+    #[serde(rename = "verified", skip_serializing_if = "Option::is_none")]
     pub verified: Option<bool>,
-    #[serde(rename = "activated_at")]
+    #[serde(rename = "activated_at", skip_serializing_if = "Option::is_none")]
     pub activated_at: Option<DateTime<Utc>>,
-    #[serde(rename = "cover_image")]
+    #[serde(
+        rename = "cover_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cover_image: Option<String>,
-    #[serde(rename = "friends_ids")]
+    #[serde(rename = "friends_ids", skip_serializing_if = "Option::is_none")]
     pub friends_ids: Option<Vec<String>>,
-    #[serde(rename = "platforms")]
+    #[serde(rename = "platforms", skip_serializing_if = "Option::is_none")]
     pub platforms: Option<std::collections::HashMap<String, String>>,
-    #[serde(rename = "settings")]
+    #[serde(rename = "settings", skip_serializing_if = "Option::is_none")]
     pub settings: Option<UserSettings>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Player {
+    /// Get the player's details for one game (e.g., "cs2")
+    ///
+    /// Returns `None` if the player has not played the game. The player
+    /// endpoint has no games filter, so details for all games are always
+    /// fetched.
+    pub fn game_detail(&self, game_id: &str) -> Option<&GameDetail> {
+        self.games.as_ref()?.get(game_id)
+    }
+
+    /// Get the player's account ID on a linked platform (e.g., "steam")
+    ///
+    /// Returns `None` if the platform is not linked or the ID is empty.
+    pub fn platform(&self, name: &str) -> Option<&str> {
+        self.platforms
+            .as_ref()?
+            .get(name)
+            .map(String::as_str)
+            .filter(|id| !id.is_empty())
+    }
+
+    /// Get the player's 64-bit Steam ID
+    ///
+    /// Uses the top-level `steam_id_64` field, falling back to the "steam"
+    /// entry of `platforms`. `new_steam_id` is not used as it holds the Steam
+    /// ID in a different format.
+    pub fn steam_id(&self) -> Option<&str> {
+        self.steam_id_64
+            .as_deref()
+            .filter(|id| !id.is_empty())
+            .or_else(|| self.platform("steam"))
+    }
+
+    /// Get the link to the player's profile on the FACEIT website
+    ///
+    /// Uses `faceit_url` with its `{lang}` placeholder filled in, or builds
+    /// the link from the nickname if the field is missing.
+    pub fn web_url(&self, language: &str) -> String {
+        match &self.faceit_url {
+            Some(url) => crate::urls::localize(url, language),
+            None => crate::urls::localize(&crate::urls::player_url(&self.nickname), language),
+        }
+    }
+
+    /// Get the player's memberships as typed values
+    pub fn membership_kinds(&self) -> Vec<Membership> {
+        self.memberships
+            .iter()
+            .flatten()
+            .map(|m| Membership::from(m.as_str()))
+            .collect()
+    }
+
+    /// Whether the player has any paid membership
+    pub fn is_premium(&self) -> bool {
+        self.membership_kinds().iter().any(Membership::is_premium)
+    }
+}
+
+impl std::fmt::Display for Player {
+    /// Formats as `nickname (player_id)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.nickname, self.player_id)
+    }
 }
 
 /// Game-specific player details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameDetail {
-    #[serde(rename = "faceit_elo")]
+    #[serde(
+        rename = "faceit_elo",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub faceit_elo: Option<i64>,
-    #[serde(rename = "game_player_id")]
+    #[serde(rename = "game_player_id", skip_serializing_if = "Option::is_none")]
     pub game_player_id: Option<String>,
-    #[serde(rename = "game_player_name")]
+    #[serde(rename = "game_player_name", skip_serializing_if = "Option::is_none")]
     pub game_player_name: Option<String>,
-    #[serde(rename = "game_profile_id")]
+    #[serde(rename = "game_profile_id", skip_serializing_if = "Option::is_none")]
     pub game_profile_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<std::collections::HashMap<String, serde_json::Value>>,
-    #[serde(rename = "skill_level")]
+    #[serde(
+        rename = "skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub skill_level: Option<i64>,
-    #[serde(rename = "skill_level_label")]
+    #[serde(rename = "skill_level_label", skip_serializing_if = "Option::is_none")]
     pub skill_level_label: Option<String>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl GameDetail {
+    /// Get every region the player is known to play in
+    ///
+    /// Combines the player's home `region` with the keys of `regions`,
+    /// sorted and without duplicates.
+    pub fn known_regions(&self) -> Vec<&str> {
+        let mut regions: Vec<&str> = self
+            .region
+            .as_deref()
+            .into_iter()
+            .chain(
+                self.regions
+                    .iter()
+                    .flat_map(|regions| regions.keys().map(String::as_str)),
+            )
+            .collect();
+        regions.sort_unstable();
+        regions.dedup();
+        regions
+    }
 }
 
 /// User settings
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct UserSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
 }
 
@@ -82,8 +593,55 @@ pub struct PlayerStats {
     pub player_id: String,
     #[serde(rename = "game_id")]
     pub game_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lifetime: Option<serde_json::Value>,
-    pub segments: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<StatsSegment>>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl PlayerStats {
+    /// Get the stats segment with the given label (e.g. a map such as "de_mirage")
+    ///
+    /// Labels are compared case-insensitively.
+    pub fn segment(&self, label: &str) -> Option<&StatsSegment> {
+        self.segments.as_deref()?.iter().find(|segment| {
+            segment
+                .label
+                .as_deref()
+                .is_some_and(|segment_label| segment_label.eq_ignore_ascii_case(label))
+        })
+    }
+
+    /// Get all stats segments for the given game mode (e.g. "5v5")
+    pub fn segments_by_mode<'a>(&'a self, mode: &'a str) -> impl Iterator<Item = &'a StatsSegment> {
+        self.segments
+            .iter()
+            .flatten()
+            .filter(move |segment| segment.mode.as_deref() == Some(mode))
+    }
+}
+
+/// Stats segment (e.g. per map) for a player or team
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsSegment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub segment_type: Option<String>,
+    #[serde(rename = "img_small", skip_serializing_if = "Option::is_none")]
+    pub img_small: Option<String>,
+    #[serde(rename = "img_regular", skip_serializing_if = "Option::is_none")]
+    pub img_regular: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Player ban information
@@ -102,6 +660,32 @@ pub struct PlayerBan {
     pub reason: String,
 }
 
+impl PlayerBan {
+    /// Bans ending more than this many years after they start are treated as
+    /// permanent, since the API represents them with a far-future `ends_at`
+    const PERMANENT_AFTER_YEARS: i64 = 50;
+
+    /// Whether the ban never expires
+    pub fn is_permanent(&self) -> bool {
+        self.ends_at - self.starts_at > chrono::Duration::days(365 * Self::PERMANENT_AFTER_YEARS)
+    }
+
+    /// Whether the ban is in effect at `now`
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at <= now && (self.is_permanent() || now < self.ends_at)
+    }
+
+    /// Get the time left on the ban at `now`
+    ///
+    /// Returns `None` if the ban is not active at `now` or is permanent.
+    pub fn remaining(&self, now: DateTime<Utc>) -> Option<std::time::Duration> {
+        if !self.is_active(now) || self.is_permanent() {
+            return None;
+        }
+        (self.ends_at - now).to_std().ok()
+    }
+}
+
 /// Player bans list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerBansList {
@@ -114,67 +698,334 @@ pub struct PlayerBansList {
 // Match Types
 // ============================================================================
 
+string_enum! {
+    /// Match status
+    pub enum MatchStatus {
+        Scheduled => "SCHEDULED",
+        CheckIn => "CHECK_IN",
+        Voting => "VOTING",
+        CaptainPick => "CAPTAIN_PICK",
+        Configuring => "CONFIGURING",
+        Ready => "READY",
+        Ongoing => "ONGOING",
+        ManualResult => "MANUAL_RESULT",
+        Paused => "PAUSED",
+        Aborted => "ABORTED",
+        Cancelled => "CANCELLED",
+        Finished => "FINISHED",
+    }
+}
+
+impl MatchStatus {
+    /// Whether the match can no longer change status
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Finished | Self::Cancelled | Self::Aborted)
+    }
+
+    /// Whether the match has not started yet
+    ///
+    /// Covers scheduling, check-in, map voting, captain picks and server
+    /// configuration.
+    pub fn is_upcoming(&self) -> bool {
+        matches!(
+            self,
+            Self::Scheduled
+                | Self::CheckIn
+                | Self::Voting
+                | Self::CaptainPick
+                | Self::Configuring
+                | Self::Ready
+        )
+    }
+
+    /// Whether the match has started but is not over yet
+    ///
+    /// Includes paused matches and matches waiting for a manual result.
+    pub fn is_ongoing(&self) -> bool {
+        matches!(self, Self::Ongoing | Self::Paused | Self::ManualResult)
+    }
+}
+
+string_enum! {
+    /// Kind of competition a match was played in, or a search result refers to
+    ///
+    /// Returned by [`Match::competition_type_kind`],
+    /// [`MatchHistory::competition_type_kind`] and
+    /// [`CompetitionSearch::competition_type_kind`].
+    pub enum CompetitionType {
+        Matchmaking => "matchmaking",
+        Hub => "hub",
+        Championship => "championship",
+        Tournament => "tournament",
+    }
+}
+
 /// Match information
+///
+/// Compared and hashed by `match_id` only.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Match {
     #[serde(rename = "match_id")]
     pub match_id: String,
     pub game: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
-    #[serde(rename = "competition_id")]
+    #[serde(rename = "competition_id", skip_serializing_if = "Option::is_none")]
     pub competition_id: Option<String>,
-    #[serde(rename = "competition_type")]
+    #[serde(rename = "competition_type", skip_serializing_if = "Option::is_none")]
     pub competition_type: Option<String>,
-    #[serde(rename = "competition_name")]
+    #[serde(rename = "competition_name", skip_serializing_if = "Option::is_none")]
     pub competition_name: Option<String>,
-    #[serde(rename = "organizer_id")]
+    #[serde(rename = "organizer_id", skip_serializing_if = "Option::is_none")]
     pub organizer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub teams: Option<std::collections::HashMap<String, Faction>>,
     pub status: String,
-    #[serde(rename = "started_at")]
+    #[serde(rename = "started_at", skip_serializing_if = "Option::is_none")]
     pub started_at: Option<i64>,
-    #[serde(rename = "finished_at")]
+    #[serde(rename = "finished_at", skip_serializing_if = "Option::is_none")]
     pub finished_at: Option<i64>,
-    #[serde(rename = "scheduled_at")]
+    #[serde(rename = "scheduled_at", skip_serializing_if = "Option::is_none")]
     pub scheduled_at: Option<i64>,
-    #[serde(rename = "configured_at")]
+    #[serde(rename = "configured_at", skip_serializing_if = "Option::is_none")]
     pub configured_at: Option<i64>,
-    #[serde(rename = "best_of")]
+    #[serde(rename = "best_of", skip_serializing_if = "Option::is_none")]
     pub best_of: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<MatchResult>,
-    #[serde(rename = "detailed_results")]
+    #[serde(rename = "detailed_results", skip_serializing_if = "Option::is_none")]
     pub detailed_results: Option<Vec<DetailedMatchResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub round: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<i64>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "chat_room_id")]
+    #[serde(rename = "chat_room_id", skip_serializing_if = "Option::is_none")]
     pub chat_room_id: Option<String>,
-    #[serde(rename = "demo_url")]
+    #[serde(rename = "demo_url", skip_serializing_if = "Option::is_none")]
     pub demo_url: Option<Vec<String>>,
-    #[serde(rename = "calculate_elo")]
+    #[serde(rename = "calculate_elo", skip_serializing_if = "Option::is_none")]
     pub calculate_elo: Option<bool>,
-    #[serde(rename = "broadcast_start_time")]
+    #[serde(
+        rename = "broadcast_start_time",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub broadcast_start_time: Option<i64>,
-    #[serde(rename = "broadcast_start_time_label")]
+    #[serde(
+        rename = "broadcast_start_time_label",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub broadcast_start_time_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub voting: Option<serde_json::Value>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Compute the time elapsed between two Unix timestamps (in seconds)
+fn duration_between(
+    started_at: Option<i64>,
+    finished_at: Option<i64>,
+) -> Option<std::time::Duration> {
+    let elapsed = finished_at?.checked_sub(started_at?)?;
+    u64::try_from(elapsed)
+        .ok()
+        .map(std::time::Duration::from_secs)
+}
+
+/// Convert a timestamp as returned by the API into a date
+///
+/// Most endpoints use Unix seconds, but championship dates are in
+/// milliseconds. Values too large to be seconds (after the year 5000) are
+/// read as milliseconds.
+fn api_timestamp(value: i64) -> Option<DateTime<Utc>> {
+    const MAX_SECONDS: i64 = 100_000_000_000;
+    if value.abs() >= MAX_SECONDS {
+        DateTime::from_timestamp_millis(value)
+    } else {
+        DateTime::from_timestamp(value, 0)
+    }
+}
+
+impl Match {
+    /// Get the status as a typed value
+    pub fn status_kind(&self) -> MatchStatus {
+        MatchStatus::from(self.status.as_str())
+    }
+
+    /// Whether statistics can be fetched for this match
+    ///
+    /// FACEIT only publishes statistics once a match has finished; cancelled
+    /// and aborted matches never get any.
+    pub fn stats_available(&self) -> bool {
+        self.status_kind() == MatchStatus::Finished
+    }
+
+    /// Get the link to the match room on the FACEIT website
+    ///
+    /// Uses `faceit_url` with its `{lang}` placeholder filled in, or builds
+    /// the link from the match and game IDs if the field is missing.
+    pub fn web_url(&self, language: &str) -> String {
+        match &self.faceit_url {
+            Some(url) => crate::urls::localize(url, language),
+            None => crate::urls::localize(
+                &crate::urls::match_url(&self.match_id, &self.game),
+                language,
+            ),
+        }
+    }
+
+    /// Get the competition type as a typed value
+    pub fn competition_type_kind(&self) -> Option<CompetitionType> {
+        self.competition_type.as_deref().map(CompetitionType::from)
+    }
+
+    /// Get how long the match lasted
+    ///
+    /// Returns `None` if the match has not started or finished yet.
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        duration_between(self.started_at, self.finished_at)
+    }
+
+    /// Get all roster members across both factions
+    ///
+    /// Factions are visited in key order (`faction1`, then `faction2`).
+    pub fn roster(&self) -> Vec<&Roster> {
+        let mut factions: Vec<(&String, &Faction)> = self.teams.iter().flatten().collect();
+        factions.sort_by_key(|(key, _)| *key);
+        factions
+            .into_iter()
+            .flat_map(|(_, faction)| faction.roster.iter().flatten())
+            .collect()
+    }
+
+    /// Parse the `voting` blob into a [`MatchVoting`]
+    ///
+    /// Returns `Ok(None)` if the match has no voting data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the voting data does not have the expected shape.
+    pub fn parsed_voting(&self) -> Result<Option<MatchVoting>, Error> {
+        self.voting
+            .as_ref()
+            .map(|voting| MatchVoting::deserialize(voting).map_err(Error::Json))
+            .transpose()
+    }
+}
+
+impl std::fmt::Display for Match {
+    /// Formats as `match_id [status]`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]", self.match_id, self.status)
+    }
+}
+
+/// Match veto results
+///
+/// The Data API only exposes the options and final picks for each voted
+/// entity type (e.g. `"map"`, `"location"`); the individual pick/ban steps
+/// and the team that made them are not available.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchVoting {
+    #[serde(rename = "voted_entity_types", default)]
+    pub voted_entity_types: Vec<String>,
+    /// Results keyed by entity type
+    #[serde(flatten)]
+    pub categories: std::collections::HashMap<String, VotingCategory>,
+}
+
+impl MatchVoting {
+    /// Get the results for an entity type
+    pub fn category(&self, entity_type: &str) -> Option<&VotingCategory> {
+        self.categories.get(entity_type)
+    }
+
+    /// Get the picked maps, in pick order
+    pub fn map_picks(&self) -> &[String] {
+        self.category("map")
+            .map(|category| category.pick.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Veto results for one entity type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VotingCategory {
+    /// Options that were available
+    #[serde(default)]
+    pub entities: Vec<VotingEntity>,
+    /// Picked entity IDs, in pick order
+    #[serde(default)]
+    pub pick: Vec<String>,
+}
+
+impl VotingCategory {
+    /// Get the picked entities, in pick order
+    pub fn picked_entities(&self) -> Vec<&VotingEntity> {
+        self.pick
+            .iter()
+            .filter_map(|id| self.entities.iter().find(|entity| entity.id() == Some(id)))
+            .collect()
+    }
+}
+
+/// Veto option (a map, server location, ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VotingEntity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guid: Option<String>,
+    #[serde(rename = "game_map_id", skip_serializing_if = "Option::is_none")]
+    pub game_map_id: Option<String>,
+    #[serde(rename = "class_name", skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub image_sm: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub image_lg: Option<String>,
+}
+
+impl VotingEntity {
+    /// Get the ID used in [`VotingCategory::pick`]
+    pub fn id(&self) -> Option<&str> {
+        self.guid
+            .as_deref()
+            .or(self.game_map_id.as_deref())
+            .or(self.class_name.as_deref())
+    }
 }
 
 /// Match result
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MatchResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub score: Option<std::collections::HashMap<String, i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub winner: Option<String>,
 }
 
 /// Detailed match result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetailedMatchResult {
-    #[serde(rename = "asc_score")]
+    #[serde(rename = "asc_score", skip_serializing_if = "Option::is_none")]
     pub asc_score: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub factions: Option<std::collections::HashMap<String, FactionResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub winner: Option<String>,
 }
 
@@ -187,15 +1038,25 @@ pub struct FactionResult {
 /// Faction (team) in a match
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Faction {
-    #[serde(rename = "faction_id")]
+    #[serde(rename = "faction_id", skip_serializing_if = "Option::is_none")]
     pub faction_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub leader: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub faction_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub roster: Option<Vec<Roster>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub substituted: Option<bool>,
 }
 
@@ -205,39 +1066,67 @@ pub struct Roster {
     #[serde(rename = "player_id")]
     pub player_id: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
-    #[serde(rename = "game_player_id")]
+    #[serde(rename = "game_player_id", skip_serializing_if = "Option::is_none")]
     pub game_player_id: Option<String>,
-    #[serde(rename = "game_player_name")]
+    #[serde(rename = "game_player_name", skip_serializing_if = "Option::is_none")]
     pub game_player_name: Option<String>,
-    #[serde(rename = "game_skill_level")]
+    #[serde(
+        rename = "game_skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub game_skill_level: Option<i64>,
-    #[serde(rename = "anticheat_required")]
+    #[serde(rename = "anticheat_required", skip_serializing_if = "Option::is_none")]
     pub anticheat_required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub membership: Option<String>,
 }
 
+impl Roster {
+    /// Get the player's membership as a typed value
+    pub fn membership_kind(&self) -> Option<Membership> {
+        self.membership.as_deref().map(Membership::from)
+    }
+
+    /// Whether the player has a paid membership
+    pub fn is_premium(&self) -> bool {
+        self.membership_kind().is_some_and(|m| m.is_premium())
+    }
+}
+
 /// Match stats
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stats {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rating: Option<i64>,
-    #[serde(rename = "skillLevel")]
+    #[serde(rename = "skillLevel", skip_serializing_if = "Option::is_none")]
     pub skill_level: Option<SkillLevel>,
-    #[serde(rename = "winProbability")]
+    #[serde(rename = "winProbability", skip_serializing_if = "Option::is_none")]
     pub win_probability: Option<f64>,
 }
 
 /// Skill level
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillLevel {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub average: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub range: Option<SkillLevelRange>,
 }
 
 /// Skill level range
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SkillLevelRange {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub min: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max: Option<i64>,
 }
 
@@ -247,46 +1136,226 @@ pub struct MatchStats {
     pub rounds: Vec<RoundStats>,
 }
 
+impl MatchStats {
+    /// Sum each player's numeric stats across all rounds (maps)
+    ///
+    /// Returns totals keyed by player ID. Stats are summed as-is, so for
+    /// per-map values such as `ADR` or `K/D Ratio` use
+    /// [`PlayerStatTotals::average`] instead of the total.
+    pub fn player_totals(&self) -> std::collections::HashMap<String, PlayerStatTotals> {
+        let mut totals: std::collections::HashMap<String, PlayerStatTotals> =
+            std::collections::HashMap::new();
+
+        let players = self
+            .rounds
+            .iter()
+            .flat_map(|round| round.teams.iter().flatten())
+            .flat_map(|team| team.players.iter().flatten());
+        for player in players {
+            let Some(player_id) = &player.player_id else {
+                continue;
+            };
+            let entry = totals.entry(player_id.clone()).or_default();
+            if entry.nickname.is_none() {
+                entry.nickname = player.nickname.clone();
+            }
+            entry.maps += 1;
+
+            let stats = player
+                .player_stats
+                .as_ref()
+                .and_then(|stats| stats.as_object());
+            for (name, value) in stats.into_iter().flatten() {
+                if let Some(value) = stat_as_f64(value) {
+                    *entry.totals.entry(name.clone()).or_default() += value;
+                }
+            }
+        }
+
+        totals
+    }
+}
+
+/// Parse a stat value, which the API returns as either a number or a numeric string
+fn stat_as_f64(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// A player's stats summed across the rounds of a match
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerStatTotals {
+    pub nickname: Option<String>,
+    /// Number of rounds (maps) the player appeared in
+    pub maps: u32,
+    /// Summed numeric stats keyed by stat name (e.g. `"Kills"`)
+    pub totals: std::collections::HashMap<String, f64>,
+}
+
+impl PlayerStatTotals {
+    /// Get the summed value of a stat
+    pub fn total(&self, stat: &str) -> Option<f64> {
+        self.totals.get(stat).copied()
+    }
+
+    /// Get the per-map average of a stat
+    pub fn average(&self, stat: &str) -> Option<f64> {
+        if self.maps == 0 {
+            return None;
+        }
+        self.total(stat).map(|total| total / f64::from(self.maps))
+    }
+}
+
 /// Round stats
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoundStats {
-    #[serde(rename = "match_id")]
+    #[serde(rename = "match_id", skip_serializing_if = "Option::is_none")]
     pub match_id: Option<String>,
-    #[serde(rename = "game_id")]
+    #[serde(rename = "game_id", skip_serializing_if = "Option::is_none")]
     pub game_id: Option<String>,
-    #[serde(rename = "competition_id")]
+    #[serde(rename = "competition_id", skip_serializing_if = "Option::is_none")]
     pub competition_id: Option<String>,
-    #[serde(rename = "game_mode")]
+    #[serde(rename = "game_mode", skip_serializing_if = "Option::is_none")]
     pub game_mode: Option<String>,
-    #[serde(rename = "match_round")]
+    #[serde(rename = "match_round", skip_serializing_if = "Option::is_none")]
     pub match_round: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub played: Option<i64>,
-    #[serde(rename = "best_of")]
+    #[serde(rename = "best_of", skip_serializing_if = "Option::is_none")]
     pub best_of: Option<i64>,
-    #[serde(rename = "round_stats")]
+    #[serde(rename = "round_stats", skip_serializing_if = "Option::is_none")]
     pub round_stats: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub teams: Option<Vec<TeamStatsSimple>>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Team stats simple
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamStatsSimple {
-    #[serde(rename = "team_id")]
+    #[serde(rename = "team_id", skip_serializing_if = "Option::is_none")]
     pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub premade: Option<bool>,
-    #[serde(rename = "team_stats")]
+    #[serde(rename = "team_stats", skip_serializing_if = "Option::is_none")]
     pub team_stats: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub players: Option<Vec<PlayerStatsSimple>>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Player stats simple
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStatsSimple {
-    #[serde(rename = "player_id")]
+    #[serde(rename = "player_id", skip_serializing_if = "Option::is_none")]
     pub player_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nickname: Option<String>,
-    #[serde(rename = "player_stats")]
+    #[serde(rename = "player_stats", skip_serializing_if = "Option::is_none")]
     pub player_stats: Option<serde_json::Value>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Typed access to a stats map keyed by stat name
+///
+/// The stats endpoints return stats as untyped maps whose values may be
+/// numbers, booleans or strings (often numeric strings such as `"1.25"`).
+/// Implemented for [`PlayerStatsSimple`], [`TeamStatsSimple`] and
+/// [`RoundStats`], so code can read stats the same way from any of them.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::types::{PlayerStatsSimple, StatMap};
+///
+/// fn kills(stats: &impl StatMap) -> i64 {
+///     stats.get_i64("Kills").unwrap_or(0)
+/// }
+///
+/// let player: PlayerStatsSimple = serde_json::from_str(
+///     r#"{"player_id": "p-1", "player_stats": {"Kills": "20", "Result": "1"}}"#,
+/// )?;
+/// assert_eq!(kills(&player), 20);
+/// assert_eq!(player.get_bool("Result"), Some(true));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub trait StatMap {
+    /// Get the raw value of a stat
+    fn stat(&self, name: &str) -> Option<&serde_json::Value>;
+
+    /// Get a stat as a float, parsing numeric strings
+    fn get_f64(&self, name: &str) -> Option<f64> {
+        self.stat(name).and_then(stat_as_f64)
+    }
+
+    /// Get a stat as an integer, parsing numeric strings
+    ///
+    /// Returns `None` for values with a fractional part.
+    fn get_i64(&self, name: &str) -> Option<i64> {
+        match self.stat(name)? {
+            serde_json::Value::Number(n) => n.as_i64(),
+            serde_json::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Get a stat as a string
+    ///
+    /// Only string values are returned; numbers are not converted.
+    fn get_str(&self, name: &str) -> Option<&str> {
+        self.stat(name)?.as_str()
+    }
+
+    /// Get a stat as a boolean
+    ///
+    /// Accepts booleans as well as `1`/`0` and `"true"`/`"false"`, in either
+    /// number or string form.
+    fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.stat(name)? {
+            serde_json::Value::Bool(b) => Some(*b),
+            serde_json::Value::Number(n) => match n.as_i64()? {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            },
+            serde_json::Value::String(s) => match s.trim() {
+                "1" => Some(true),
+                "0" => Some(false),
+                s if s.eq_ignore_ascii_case("true") => Some(true),
+                s if s.eq_ignore_ascii_case("false") => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl StatMap for PlayerStatsSimple {
+    fn stat(&self, name: &str) -> Option<&serde_json::Value> {
+        self.player_stats.as_ref()?.get(name)
+    }
+}
+
+impl StatMap for TeamStatsSimple {
+    fn stat(&self, name: &str) -> Option<&serde_json::Value> {
+        self.team_stats.as_ref()?.get(name)
+    }
+}
+
+impl StatMap for RoundStats {
+    fn stat(&self, name: &str) -> Option<&serde_json::Value> {
+        self.round_stats.as_ref()?.get(name)
+    }
 }
 
 /// Match history entry
@@ -296,46 +1365,84 @@ pub struct MatchHistory {
     pub match_id: String,
     #[serde(rename = "game_id")]
     pub game_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
-    #[serde(rename = "match_type")]
+    #[serde(rename = "match_type", skip_serializing_if = "Option::is_none")]
     pub match_type: Option<String>,
-    #[serde(rename = "game_mode")]
+    #[serde(rename = "game_mode", skip_serializing_if = "Option::is_none")]
     pub game_mode: Option<String>,
-    #[serde(rename = "max_players")]
+    #[serde(rename = "max_players", skip_serializing_if = "Option::is_none")]
     pub max_players: Option<i64>,
-    #[serde(rename = "teams_size")]
+    #[serde(rename = "teams_size", skip_serializing_if = "Option::is_none")]
     pub teams_size: Option<i64>,
-    #[serde(rename = "teams")]
+    #[serde(rename = "teams", skip_serializing_if = "Option::is_none")]
     pub teams: Option<std::collections::HashMap<String, HistoryFaction>>,
-    #[serde(rename = "playing_players")]
+    #[serde(rename = "playing_players", skip_serializing_if = "Option::is_none")]
     pub playing_players: Option<Vec<String>>,
-    #[serde(rename = "competition_id")]
+    #[serde(rename = "competition_id", skip_serializing_if = "Option::is_none")]
     pub competition_id: Option<String>,
-    #[serde(rename = "competition_name")]
+    #[serde(rename = "competition_name", skip_serializing_if = "Option::is_none")]
     pub competition_name: Option<String>,
-    #[serde(rename = "competition_type")]
+    #[serde(rename = "competition_type", skip_serializing_if = "Option::is_none")]
     pub competition_type: Option<String>,
-    #[serde(rename = "organizer_id")]
+    #[serde(rename = "organizer_id", skip_serializing_if = "Option::is_none")]
     pub organizer_id: Option<String>,
-    #[serde(rename = "started_at")]
+    #[serde(rename = "started_at", skip_serializing_if = "Option::is_none")]
     pub started_at: Option<i64>,
-    #[serde(rename = "finished_at")]
+    #[serde(rename = "finished_at", skip_serializing_if = "Option::is_none")]
     pub finished_at: Option<i64>,
     pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<MatchResult>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
 }
 
+impl MatchHistory {
+    /// Get the competition type as a typed value
+    pub fn competition_type_kind(&self) -> Option<CompetitionType> {
+        self.competition_type.as_deref().map(CompetitionType::from)
+    }
+
+    /// Get how long the match lasted
+    ///
+    /// Returns `None` if the match has not started or finished yet.
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        duration_between(self.started_at, self.finished_at)
+    }
+
+    /// Whether the given player was on the winning faction
+    ///
+    /// Returns `None` if the match has no winner yet or the player is not on
+    /// either team.
+    pub fn player_won(&self, player_id: &str) -> Option<bool> {
+        let winner = self.results.as_ref()?.winner.as_deref()?;
+        let (faction, _) = self.teams.as_ref()?.iter().find(|(_, team)| {
+            team.players
+                .iter()
+                .flatten()
+                .any(|player| player.player_id == player_id)
+        })?;
+        Some(faction == winner)
+    }
+}
+
 /// History faction
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryFaction {
-    #[serde(rename = "team_id")]
+    #[serde(rename = "team_id", skip_serializing_if = "Option::is_none")]
     pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nickname: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub faction_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub players: Option<Vec<MatchHistoryPlayer>>,
 }
 
@@ -345,17 +1452,135 @@ pub struct MatchHistoryPlayer {
     #[serde(rename = "player_id")]
     pub player_id: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "game_player_id")]
+    #[serde(rename = "game_player_id", skip_serializing_if = "Option::is_none")]
     pub game_player_id: Option<String>,
-    #[serde(rename = "game_player_name")]
+    #[serde(rename = "game_player_name", skip_serializing_if = "Option::is_none")]
     pub game_player_name: Option<String>,
-    #[serde(rename = "skill_level")]
+    #[serde(
+        rename = "skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub skill_level: Option<i64>,
 }
 
+/// Best-effort [`Match`] rebuilt from a match history entry
+///
+/// Used when the match itself can no longer be fetched (see
+/// [`Client::get_match_or_history`](crate::http::Client::get_match_or_history)).
+/// History entries only carry a summary, so the result has no
+/// `detailed_results`, `voting`, `demo_url`, `chat_room_id`, scheduling or
+/// broadcast times, and its rosters lack memberships and anti-cheat flags.
+/// Team names come from each faction's nickname. History fields that
+/// [`Match`] does not model (`game_mode`, `match_type`, `max_players`,
+/// `teams_size` and `playing_players`) are kept in
+/// [`extra`](Match::extra).
+impl From<MatchHistory> for Match {
+    fn from(history: MatchHistory) -> Self {
+        let mut extra = std::collections::HashMap::new();
+        let unmodelled = [
+            ("game_mode", history.game_mode.map(serde_json::Value::from)),
+            (
+                "match_type",
+                history.match_type.map(serde_json::Value::from),
+            ),
+            (
+                "max_players",
+                history.max_players.map(serde_json::Value::from),
+            ),
+            (
+                "teams_size",
+                history.teams_size.map(serde_json::Value::from),
+            ),
+            (
+                "playing_players",
+                history.playing_players.map(serde_json::Value::from),
+            ),
+        ];
+        for (key, value) in unmodelled {
+            if let Some(value) = value {
+                extra.insert(key.to_string(), value);
+            }
+        }
+
+        Self {
+            match_id: history.match_id,
+            game: history.game_id,
+            region: history.region,
+            competition_id: history.competition_id,
+            competition_type: history.competition_type,
+            competition_name: history.competition_name,
+            organizer_id: history.organizer_id,
+            teams: history.teams.map(|teams| {
+                teams
+                    .into_iter()
+                    .map(|(faction, team)| (faction, Faction::from(team)))
+                    .collect()
+            }),
+            status: history.status,
+            started_at: history.started_at,
+            finished_at: history.finished_at,
+            scheduled_at: None,
+            configured_at: None,
+            best_of: None,
+            results: history.results,
+            detailed_results: None,
+            round: None,
+            group: None,
+            faceit_url: history.faceit_url,
+            chat_room_id: None,
+            demo_url: None,
+            calculate_elo: None,
+            broadcast_start_time: None,
+            broadcast_start_time_label: None,
+            version: None,
+            voting: None,
+            extra,
+        }
+    }
+}
+
+impl From<HistoryFaction> for Faction {
+    fn from(faction: HistoryFaction) -> Self {
+        Self {
+            faction_id: faction.team_id,
+            leader: None,
+            avatar: faction.avatar,
+            name: faction.nickname,
+            faction_type: faction.faction_type,
+            roster: faction
+                .players
+                .map(|players| players.into_iter().map(Roster::from).collect()),
+            stats: None,
+            substituted: None,
+        }
+    }
+}
+
+impl From<MatchHistoryPlayer> for Roster {
+    fn from(player: MatchHistoryPlayer) -> Self {
+        Self {
+            player_id: player.player_id,
+            nickname: player.nickname,
+            avatar: player.avatar,
+            game_player_id: player.game_player_id,
+            game_player_name: player.game_player_name,
+            game_skill_level: player.skill_level,
+            anticheat_required: None,
+            membership: None,
+        }
+    }
+}
+
 // ============================================================================
 // List Response Types
 // ============================================================================
@@ -368,6 +1593,23 @@ pub struct GamesList {
     pub items: Vec<Game>,
 }
 
+impl GamesList {
+    /// Get the games in FACEIT's display order
+    ///
+    /// Sorts by [`Game::order`] ascending. Games without an order come last,
+    /// and games with equal order keep their API order.
+    pub fn sorted_by_order(&self) -> Vec<&Game> {
+        let mut games: Vec<&Game> = self.items.iter().collect();
+        sort_by_display_order(&mut games, |game| game.order);
+        games
+    }
+}
+
+/// Stable sort by an optional display order, missing orders last
+pub(crate) fn sort_by_display_order<T>(items: &mut [T], order: impl Fn(&T) -> Option<i64>) {
+    items.sort_by_key(|item| (order(item).is_none(), order(item)));
+}
+
 /// Matches list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchesList {
@@ -376,21 +1618,156 @@ pub struct MatchesList {
     pub items: Vec<Match>,
 }
 
+impl MatchesList {
+    /// Get the matches with the given status
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, http::MatchType, types::MatchStatus};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let matches = client.get_hub_matches("hub-id", Some(MatchType::All), Some(0), Some(100)).await?;
+    /// for match_data in matches.filter_status(MatchStatus::Voting) {
+    ///     println!("{} is picking maps", match_data.match_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter_status(&self, status: MatchStatus) -> Vec<&Match> {
+        self.items
+            .iter()
+            .filter(|m| m.status_kind() == status)
+            .collect()
+    }
+
+    /// Get the matches that have not started yet (see [`MatchStatus::is_upcoming`])
+    pub fn upcoming(&self) -> Vec<&Match> {
+        self.filter_kind(MatchStatus::is_upcoming)
+    }
+
+    /// Get the matches being played (see [`MatchStatus::is_ongoing`])
+    pub fn ongoing(&self) -> Vec<&Match> {
+        self.filter_kind(MatchStatus::is_ongoing)
+    }
+
+    /// Get the finished matches, excluding cancelled and aborted ones
+    pub fn finished(&self) -> Vec<&Match> {
+        self.filter_status(MatchStatus::Finished)
+    }
+
+    fn filter_kind(&self, predicate: impl Fn(&MatchStatus) -> bool) -> Vec<&Match> {
+        self.items
+            .iter()
+            .filter(|m| predicate(&m.status_kind()))
+            .collect()
+    }
+}
+
 /// Match history list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchHistoryList {
     pub start: i64,
     pub end: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<i64>,
     pub items: Vec<MatchHistory>,
 }
 
+impl MatchHistoryList {
+    /// Get the matches played in a given kind of competition
+    ///
+    /// The history endpoint cannot filter by competition, so this filters the
+    /// fetched page on `competition_type`. Accepts a [`CompetitionType`] or a
+    /// string such as `"matchmaking"`; known types are matched ignoring case.
+    /// Matches without a competition type are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::{HttpClient, types::CompetitionType};
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let history = client.get_player_history("player-id", "cs2", None, None, Some(0), Some(100)).await?;
+    /// let ranked = history.filter_by_type(CompetitionType::Matchmaking);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter_by_type(
+        &self,
+        competition_type: impl Into<CompetitionType>,
+    ) -> Vec<&MatchHistory> {
+        let competition_type = competition_type.into();
+        self.items
+            .iter()
+            .filter(|history| history.competition_type_kind().as_ref() == Some(&competition_type))
+            .collect()
+    }
+}
+
+list_type! {
+    PlayerBansList => PlayerBan,
+    GamesList => Game,
+    MatchesList => Match,
+    MatchHistoryList => MatchHistory,
+    HubMembers => HubUser,
+    HubRolesList => HubRole,
+    HubsList => Hub,
+    ChampionshipsList => Championship,
+    ChampionshipSubscriptionsList => ChampionshipSubscription,
+    TeamList => Team,
+    UsersSearchList => UserSearch,
+    TeamsSearchList => TeamSearch,
+    CompetitionsSearchList => CompetitionSearch,
+    GlobalRankingList => GlobalRanking,
+    LeaderboardsList => Leaderboard,
+    LeaderboardRanking => LeaderboardEntry,
+    PlayerGlobalRanking => GlobalRanking,
+    TournamentsList => TournamentSimple,
+    MatchmakingList => MatchmakingSlim,
+}
+
 // ============================================================================
 // Game Types
 // ============================================================================
 
+string_enum! {
+    /// FACEIT game ID
+    ///
+    /// FACEIT moved Counter-Strike from `csgo` to `cs2`. Historical data is
+    /// still stored under `csgo`, while current data uses `cs2`, so querying
+    /// with the wrong ID silently returns empty results. Common aliases such as
+    /// `"cs:go"` are accepted when parsing.
+    pub enum GameId {
+        Cs2 => "cs2" | "counter-strike 2" | "counter_strike_2",
+        /// Deprecated in favour of [`GameId::Cs2`]
+        Csgo => "csgo" | "cs:go" | "cs_go" | "counter-strike: global offensive",
+        Dota2 => "dota2" | "dota 2",
+        Pubg => "pubg",
+        RocketLeague => "rocket_league" | "rocket league",
+    }
+}
+
+impl GameId {
+    /// Whether this game ID has been superseded by another
+    pub fn is_deprecated(&self) -> bool {
+        matches!(self, Self::Csgo)
+    }
+
+    /// Get the current game ID, mapping deprecated IDs to their replacement
+    pub fn current(&self) -> GameId {
+        match self {
+            Self::Csgo => Self::Cs2,
+            other => other.clone(),
+        }
+    }
+}
+
 /// Game information
+///
+/// Compared and hashed by `game_id` only.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
     #[serde(rename = "game_id")]
@@ -399,75 +1776,230 @@ pub struct Game {
     pub short_label: String,
     #[serde(rename = "long_label")]
     pub long_label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub assets: Option<GameAssets>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub platforms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i64>,
-    #[serde(rename = "parent_game_id")]
+    #[serde(rename = "parent_game_id", skip_serializing_if = "Option::is_none")]
     pub parent_game_id: Option<String>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl std::fmt::Display for Game {
+    /// Formats as the game's long label (e.g. `Counter-Strike 2`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.long_label)
+    }
 }
 
 /// Game assets
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameAssets {
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cover: Option<String>,
-    #[serde(rename = "featured_img_l")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub featured_img_l: Option<String>,
-    #[serde(rename = "featured_img_m")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub featured_img_m: Option<String>,
-    #[serde(rename = "featured_img_s")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub featured_img_s: Option<String>,
-    #[serde(rename = "flag_img_icon")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub flag_img_icon: Option<String>,
-    #[serde(rename = "flag_img_l")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub flag_img_l: Option<String>,
-    #[serde(rename = "flag_img_m")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub flag_img_m: Option<String>,
-    #[serde(rename = "flag_img_s")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub flag_img_s: Option<String>,
-    #[serde(rename = "landing_page")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub landing_page: Option<String>,
 }
 
+/// Size variant of a game image, see [`GameAssets::featured`] and [`GameAssets::flag`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageSize {
+    /// Icon size (only available for flags)
+    Icon,
+    /// Small (`_s`)
+    Small,
+    /// Medium (`_m`)
+    Medium,
+    /// Large (`_l`)
+    Large,
+}
+
+impl GameAssets {
+    /// Get the featured image URL in the given size
+    ///
+    /// Returns `None` if the API did not provide that size. There is no
+    /// featured image in [`ImageSize::Icon`] size.
+    pub fn featured(&self, size: ImageSize) -> Option<&str> {
+        match size {
+            ImageSize::Icon => None,
+            ImageSize::Small => self.featured_img_s.as_deref(),
+            ImageSize::Medium => self.featured_img_m.as_deref(),
+            ImageSize::Large => self.featured_img_l.as_deref(),
+        }
+    }
+
+    /// Get the flag image URL in the given size
+    ///
+    /// Returns `None` if the API did not provide that size.
+    pub fn flag(&self, size: ImageSize) -> Option<&str> {
+        match size {
+            ImageSize::Icon => self.flag_img_icon.as_deref(),
+            ImageSize::Small => self.flag_img_s.as_deref(),
+            ImageSize::Medium => self.flag_img_m.as_deref(),
+            ImageSize::Large => self.flag_img_l.as_deref(),
+        }
+    }
+}
+
 // ============================================================================
 // Hub Types
 // ============================================================================
 
+string_enum! {
+    /// Who may join a hub or championship
+    ///
+    /// Used for both [`Hub::join_permission`] and [`JoinCheck::join_policy`].
+    pub enum JoinPermission {
+        Public => "public",
+        Private => "private",
+        InviteOnly => "invite_only",
+    }
+}
+
 /// Hub information
+///
+/// Compared and hashed by `hub_id` only.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hub {
     #[serde(rename = "hub_id")]
     pub hub_id: String,
     pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
     #[serde(rename = "game_id")]
     pub game_id: String,
-    #[serde(rename = "game_data")]
+    /// Only set when the hub is requested with `expanded` including `"game"`
+    #[serde(rename = "game_data", skip_serializing_if = "Option::is_none")]
     pub game_data: Option<Game>,
     #[serde(rename = "organizer_id")]
     pub organizer_id: String,
-    #[serde(rename = "organizer_data")]
+    /// Only set when the hub is requested with `expanded` including `"organizer"`
+    #[serde(rename = "organizer_data", skip_serializing_if = "Option::is_none")]
     pub organizer_data: Option<Organizer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "cover_image")]
+    #[serde(
+        rename = "cover_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cover_image: Option<String>,
-    #[serde(rename = "background_image")]
+    #[serde(
+        rename = "background_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub background_image: Option<String>,
-    #[serde(rename = "chat_room_id")]
+    #[serde(rename = "chat_room_id", skip_serializing_if = "Option::is_none")]
     pub chat_room_id: Option<String>,
-    #[serde(rename = "join_permission")]
+    #[serde(rename = "join_permission", skip_serializing_if = "Option::is_none")]
     pub join_permission: Option<String>,
-    #[serde(rename = "min_skill_level")]
+    #[serde(
+        rename = "min_skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_skill_level: Option<i64>,
-    #[serde(rename = "max_skill_level")]
+    #[serde(
+        rename = "max_skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_skill_level: Option<i64>,
-    #[serde(rename = "players_joined")]
+    #[serde(rename = "players_joined", skip_serializing_if = "Option::is_none")]
     pub players_joined: Option<i64>,
-    #[serde(rename = "rule_id")]
+    #[serde(rename = "rule_id", skip_serializing_if = "Option::is_none")]
     pub rule_id: Option<String>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Hub {
+    /// Get the join permission as a typed value
+    pub fn join_permission_kind(&self) -> Option<JoinPermission> {
+        self.join_permission.as_deref().map(JoinPermission::from)
+    }
+
+    /// Whether anyone may join the hub without an invite
+    pub fn is_public(&self) -> bool {
+        self.join_permission_kind() == Some(JoinPermission::Public)
+    }
+}
+
+impl std::fmt::Display for Hub {
+    /// Formats as `name (hub_id)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.hub_id)
+    }
 }
 
 /// Hub members list
@@ -484,12 +2016,57 @@ pub struct HubUser {
     #[serde(rename = "user_id")]
     pub user_id: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
+    /// Role IDs, see [`HubUser::resolve_roles`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<String>>,
 }
 
+impl HubUser {
+    /// Look up the member's role IDs in a hub's role catalog
+    ///
+    /// Roles are returned in the order the member lists them. IDs missing from
+    /// `roles` are skipped.
+    pub fn resolve_roles(&self, roles: &[HubRole]) -> Vec<HubRole> {
+        self.roles
+            .iter()
+            .flatten()
+            .filter_map(|id| roles.iter().find(|role| &role.role_id == id))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Hub roles list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HubRolesList {
+    pub start: i64,
+    pub end: i64,
+    pub items: Vec<HubRole>,
+}
+
+/// Hub role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HubRole {
+    #[serde(rename = "role_id")]
+    pub role_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Sort position of the role within the hub
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking: Option<i64>,
+    #[serde(rename = "visible_on_chat", skip_serializing_if = "Option::is_none")]
+    pub visible_on_chat: Option<bool>,
+}
+
 /// Hub stats
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HubStats {
@@ -505,6 +2082,9 @@ pub struct StatsCompetitionPlayer {
     pub player_id: String,
     pub nickname: String,
     pub stats: serde_json::Value,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Hubs list
@@ -515,107 +2095,428 @@ pub struct HubsList {
     pub items: Vec<Hub>,
 }
 
+/// Hub matches in a time window with their statistics
+///
+/// Returned by [`Client::get_hub_report`](crate::http::Client::get_hub_report).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HubReport {
+    pub hub_id: String,
+    /// Start of the window (Unix time, inclusive)
+    pub from: i64,
+    /// End of the window (Unix time, exclusive)
+    pub to: i64,
+    /// Matches in the window, newest first
+    pub matches: Vec<HubReportMatch>,
+}
+
+/// A match in a [`HubReport`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HubReportMatch {
+    #[serde(rename = "match")]
+    pub match_data: Match,
+    /// Match statistics, if requested and available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<MatchStats>,
+}
+
+impl HubReport {
+    /// Get the number of matches in the report
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Whether the report contains no matches
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// Sum each player's stats across all matches with statistics
+    ///
+    /// See [`MatchStats::player_totals`]; `maps` counts maps across matches.
+    pub fn player_totals(&self) -> std::collections::HashMap<String, PlayerStatTotals> {
+        let mut totals: std::collections::HashMap<String, PlayerStatTotals> =
+            std::collections::HashMap::new();
+
+        for stats in self.matches.iter().filter_map(|m| m.stats.as_ref()) {
+            for (player_id, match_totals) in stats.player_totals() {
+                let entry = totals.entry(player_id).or_default();
+                if entry.nickname.is_none() {
+                    entry.nickname = match_totals.nickname;
+                }
+                entry.maps += match_totals.maps;
+                for (name, value) in match_totals.totals {
+                    *entry.totals.entry(name).or_default() += value;
+                }
+            }
+        }
+
+        totals
+    }
+}
+
 // ============================================================================
 // Championship Types
 // ============================================================================
 
 /// Championship information
+///
+/// Compared and hashed by `championship_id` only.
+///
+/// Older responses only carry the deprecated `id`; it is used as `championship_id`
+/// when that field is missing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Championship {
     #[serde(rename = "championship_id")]
     pub championship_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>, // Deprecated
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(rename = "game_id")]
     pub game_id: String,
-    #[serde(rename = "game_data")]
+    #[serde(rename = "game_data", skip_serializing_if = "Option::is_none")]
     pub game_data: Option<Game>,
     #[serde(rename = "organizer_id")]
     pub organizer_id: String,
-    #[serde(rename = "organizer_data")]
+    #[serde(rename = "organizer_data", skip_serializing_if = "Option::is_none")]
     pub organizer_data: Option<Organizer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
-    #[serde(rename = "cover_image")]
+    #[serde(
+        rename = "cover_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cover_image: Option<String>,
-    #[serde(rename = "background_image")]
+    #[serde(
+        rename = "background_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub background_image: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
     pub status: String,
-    #[serde(rename = "championship_start")]
+    #[serde(rename = "championship_start", skip_serializing_if = "Option::is_none")]
     pub championship_start: Option<i64>,
-    #[serde(rename = "subscription_start")]
+    #[serde(rename = "subscription_start", skip_serializing_if = "Option::is_none")]
     pub subscription_start: Option<i64>,
-    #[serde(rename = "subscription_end")]
+    #[serde(rename = "subscription_end", skip_serializing_if = "Option::is_none")]
     pub subscription_end: Option<i64>,
-    #[serde(rename = "checkin_start")]
+    #[serde(rename = "checkin_start", skip_serializing_if = "Option::is_none")]
     pub checkin_start: Option<i64>,
-    #[serde(rename = "checkin_clear")]
+    #[serde(rename = "checkin_clear", skip_serializing_if = "Option::is_none")]
     pub checkin_clear: Option<i64>,
-    #[serde(rename = "checkin_enabled")]
+    #[serde(rename = "checkin_enabled", skip_serializing_if = "Option::is_none")]
     pub checkin_enabled: Option<bool>,
-    #[serde(rename = "current_subscriptions")]
+    #[serde(
+        rename = "current_subscriptions",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub current_subscriptions: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slots: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub full: Option<bool>,
-    #[serde(rename = "subscriptions_locked")]
+    #[serde(
+        rename = "subscriptions_locked",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub subscriptions_locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub featured: Option<bool>,
-    #[serde(rename = "anticheat_required")]
+    #[serde(rename = "anticheat_required", skip_serializing_if = "Option::is_none")]
     pub anticheat_required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prizes: Option<Vec<Prize>>,
-    #[serde(rename = "total_prizes")]
+    #[serde(rename = "total_prizes", skip_serializing_if = "Option::is_none")]
     pub total_prizes: Option<i64>,
-    #[serde(rename = "total_rounds")]
+    #[serde(rename = "total_rounds", skip_serializing_if = "Option::is_none")]
     pub total_rounds: Option<i64>,
-    #[serde(rename = "total_groups")]
+    #[serde(rename = "total_groups", skip_serializing_if = "Option::is_none")]
     pub total_groups: Option<i64>,
-    #[serde(rename = "seeding_strategy")]
+    #[serde(rename = "seeding_strategy", skip_serializing_if = "Option::is_none")]
     pub seeding_strategy: Option<String>,
-    #[serde(rename = "rules_id")]
+    #[serde(rename = "rules_id", skip_serializing_if = "Option::is_none")]
     pub rules_id: Option<String>,
-    #[serde(rename = "join_checks")]
+    #[serde(rename = "join_checks", skip_serializing_if = "Option::is_none")]
     pub join_checks: Option<JoinCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub schedule: Option<std::collections::HashMap<String, ChampionshipSchedule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub screening: Option<ChampionshipScreening>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<ChampionshipStream>,
-    #[serde(rename = "substitution_configuration")]
+    #[serde(
+        rename = "substitution_configuration",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub substitution_configuration: Option<SubstitutionConfiguration>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub championship_type: Option<String>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Championship {
+    /// Get the total FACEIT points awarded across all prizes
+    pub fn total_faceit_points(&self) -> i64 {
+        self.prizes
+            .iter()
+            .flatten()
+            .filter_map(|prize| prize.faceit_points)
+            .sum()
+    }
+
+    /// Get the prize awarded for the given rank
+    pub fn prize_for_rank(&self, rank: i64) -> Option<&Prize> {
+        self.prizes
+            .as_deref()?
+            .iter()
+            .find(|prize| prize.rank == rank)
+    }
+
+    /// Get the prizes for the top `n` ranks, ordered by rank
+    pub fn top_prizes(&self, n: usize) -> Vec<&Prize> {
+        let mut prizes: Vec<&Prize> = self.prizes.iter().flatten().collect();
+        prizes.sort_by_key(|prize| prize.rank);
+        prizes.truncate(n);
+        prizes
+    }
+
+    /// Get the schedule phases ordered by date
+    ///
+    /// Phases sharing a date are ordered by name so the result is deterministic.
+    pub fn schedule_sorted(&self) -> Vec<(&str, &ChampionshipSchedule)> {
+        let mut phases: Vec<(&str, &ChampionshipSchedule)> = self
+            .schedule
+            .iter()
+            .flatten()
+            .map(|(name, phase)| (name.as_str(), phase))
+            .collect();
+        phases.sort_by(|a, b| a.1.date.cmp(&b.1.date).then(a.0.cmp(b.0)));
+        phases
+    }
+
+    /// Get the status as a typed value
+    pub fn status_kind(&self) -> ChampionshipStatus {
+        ChampionshipStatus::from(self.status.as_str())
+    }
+
+    /// Get the seeding strategy as a typed value
+    pub fn seeding_strategy_kind(&self) -> Option<SeedingStrategy> {
+        self.seeding_strategy.as_deref().map(SeedingStrategy::from)
+    }
+
+    /// Get the first phase scheduled after `now`
+    ///
+    /// `now` must use the same unit as [`ChampionshipSchedule::date`].
+    pub fn next_phase(&self, now: i64) -> Option<(&str, &ChampionshipSchedule)> {
+        self.schedule_sorted()
+            .into_iter()
+            .find(|(_, phase)| phase.date > now)
+    }
+
+    /// Whether a team can still register at `now`
+    ///
+    /// Registration is open when `now` is within the subscription window
+    /// (`subscription_start` inclusive, `subscription_end` exclusive),
+    /// subscriptions are not locked, and the championship is not full, either
+    /// by its `full` flag or because `current_subscriptions` reached `slots`.
+    /// Missing window bounds and flags do not close registration. Timestamps
+    /// in seconds and in milliseconds are both accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use faceit::types::Championship;
+    /// # fn example(championship: &Championship) {
+    /// if championship.registration_open(chrono::Utc::now()) {
+    ///     println!("Join {}", championship.name);
+    /// }
+    /// # }
+    /// ```
+    pub fn registration_open(&self, now: DateTime<Utc>) -> bool {
+        let started = self
+            .subscription_start
+            .and_then(api_timestamp)
+            .is_none_or(|start| now >= start);
+        let ended = self
+            .subscription_end
+            .and_then(api_timestamp)
+            .is_some_and(|end| now >= end);
+        let locked = self.subscriptions_locked == Some(true);
+        let full = self.full == Some(true)
+            || matches!(
+                (self.current_subscriptions, self.slots),
+                (Some(current), Some(slots)) if current >= slots
+            );
+
+        started && !ended && !locked && !full
+    }
+
+    /// Whether teams can check in at `now`
+    ///
+    /// Check-in is open from `checkin_start` (inclusive) until `checkin_clear`
+    /// (exclusive), when teams that have not checked in are removed. Without
+    /// `checkin_clear`, it closes when the championship starts. It is never
+    /// open if `checkin_enabled` is `false` or `checkin_start` is missing.
+    /// Timestamps in seconds and in milliseconds are both accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use faceit::types::Championship;
+    /// # fn example(championship: &Championship) {
+    /// if championship.checkin_open(chrono::Utc::now()) {
+    ///     println!("Check in for {} now", championship.name);
+    /// }
+    /// # }
+    /// ```
+    pub fn checkin_open(&self, now: DateTime<Utc>) -> bool {
+        let Some(start) = self.checkin_start_at() else {
+            return false;
+        };
+        let closed = self
+            .checkin_clear
+            .or(self.championship_start)
+            .and_then(api_timestamp)
+            .is_some_and(|end| now >= end);
+
+        now >= start && !closed
+    }
+
+    /// Get the time left until check-in opens at `now`
+    ///
+    /// Returns `None` if check-in is disabled, has no start time, or has
+    /// already opened by `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use faceit::types::Championship;
+    /// # fn example(championship: &Championship) {
+    /// if let Some(wait) = championship.time_until_checkin(chrono::Utc::now()) {
+    ///     println!("Check-in opens in {} minutes", wait.as_secs() / 60);
+    /// }
+    /// # }
+    /// ```
+    pub fn time_until_checkin(&self, now: DateTime<Utc>) -> Option<std::time::Duration> {
+        let start = self.checkin_start_at()?;
+        if now >= start {
+            return None;
+        }
+        (start - now).to_std().ok()
+    }
+
+    /// Get when check-in opens, if check-in is enabled
+    fn checkin_start_at(&self) -> Option<DateTime<Utc>> {
+        if self.checkin_enabled == Some(false) {
+            return None;
+        }
+        self.checkin_start.and_then(api_timestamp)
+    }
 }
 
 /// Prize
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Prize {
     pub rank: i64,
-    #[serde(rename = "faceit_points")]
+    #[serde(rename = "faceit_points", skip_serializing_if = "Option::is_none")]
     pub faceit_points: Option<i64>,
 }
 
+string_enum! {
+    /// Lifecycle status of a championship or tournament
+    pub enum ChampionshipStatus {
+        Created => "created",
+        /// Registration is open
+        Join => "join",
+        CheckingIn => "checking_in",
+        Seeding => "seeding",
+        Adjustment => "adjustment",
+        Started => "started",
+        Finished => "finished",
+        Cancelled => "cancelled",
+        Aborted => "aborted",
+    }
+}
+
+impl ChampionshipStatus {
+    /// Whether the competition is over and can no longer change status
+    pub fn is_over(&self) -> bool {
+        matches!(self, Self::Finished | Self::Cancelled | Self::Aborted)
+    }
+}
+
+string_enum! {
+    /// How teams are seeded into a championship bracket
+    pub enum SeedingStrategy {
+        Random => "random",
+        Manual => "manual",
+        /// Seeded by skill level or ELO
+        Skill => "skill",
+    }
+}
+
 /// Join check
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JoinCheck {
-    #[serde(rename = "join_policy")]
+    #[serde(rename = "join_policy", skip_serializing_if = "Option::is_none")]
     pub join_policy: Option<String>,
-    #[serde(rename = "membership_type")]
+    #[serde(rename = "membership_type", skip_serializing_if = "Option::is_none")]
     pub membership_type: Option<String>,
-    #[serde(rename = "min_skill_level")]
+    #[serde(
+        rename = "min_skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_skill_level: Option<i64>,
-    #[serde(rename = "max_skill_level")]
+    #[serde(
+        rename = "max_skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_skill_level: Option<i64>,
-    #[serde(rename = "allowed_team_types")]
+    #[serde(rename = "allowed_team_types", skip_serializing_if = "Option::is_none")]
     pub allowed_team_types: Option<Vec<String>>,
-    #[serde(rename = "whitelist_geo_countries")]
+    #[serde(
+        rename = "whitelist_geo_countries",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub whitelist_geo_countries: Option<Vec<String>>,
-    #[serde(rename = "whitelist_geo_countries_min_players")]
+    #[serde(
+        rename = "whitelist_geo_countries_min_players",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub whitelist_geo_countries_min_players: Option<i64>,
-    #[serde(rename = "blacklist_geo_countries")]
+    #[serde(
+        rename = "blacklist_geo_countries",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub blacklist_geo_countries: Option<Vec<String>>,
 }
 
+impl JoinCheck {
+    /// Get the join policy as a typed value
+    pub fn join_policy_kind(&self) -> Option<JoinPermission> {
+        self.join_policy.as_deref().map(JoinPermission::from)
+    }
+}
+
 /// Championship schedule
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ChampionshipSchedule {
@@ -634,17 +2535,20 @@ pub struct ChampionshipScreening {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChampionshipStream {
     pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub platform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 }
 
 /// Substitution configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubstitutionConfiguration {
-    #[serde(rename = "max_substitutes")]
+    #[serde(rename = "max_substitutes", skip_serializing_if = "Option::is_none")]
     pub max_substitutes: Option<i64>,
-    #[serde(rename = "max_substitutions")]
+    #[serde(rename = "max_substitutions", skip_serializing_if = "Option::is_none")]
     pub max_substitutions: Option<i64>,
 }
 
@@ -656,6 +2560,56 @@ pub struct ChampionshipsList {
     pub items: Vec<Championship>,
 }
 
+/// Team signed up for a championship
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChampionshipSubscription {
+    pub team: SubscriptionTeam,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leader: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coleader: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roster: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub substitutes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coaches: Option<Vec<String>>,
+}
+
+/// Team summary embedded in a [`ChampionshipSubscription`]
+///
+/// Fetch the full [`Team`] with
+/// [`Client::get_team`](crate::http::Client::get_team).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionTeam {
+    #[serde(rename = "team_id")]
+    pub team_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub avatar: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub team_type: Option<String>,
+}
+
+/// Championship subscriptions list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChampionshipSubscriptionsList {
+    pub start: i64,
+    pub end: i64,
+    pub items: Vec<ChampionshipSubscription>,
+}
+
 // ============================================================================
 // Organizer Types
 // ============================================================================
@@ -666,51 +2620,99 @@ pub struct Organizer {
     #[serde(rename = "organizer_id")]
     pub organizer_id: String,
     pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cover: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub twitter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub youtube: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub twitch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub facebook: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub vk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub website: Option<String>,
-    #[serde(rename = "followers_count")]
+    #[serde(rename = "followers_count", skip_serializing_if = "Option::is_none")]
     pub followers_count: Option<i64>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub organizer_type: Option<String>,
 }
 
+/// An organizer with its hubs and active championships
+///
+/// Returned by [`Client::get_organizer_overview`](crate::http::Client::get_organizer_overview).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizerOverview {
+    pub organizer: Organizer,
+    /// First page of the organizer's hubs
+    pub hubs: HubsList,
+    /// Championships from the first page that are not over (see [`ChampionshipStatus::is_over`])
+    pub championships: Vec<Championship>,
+}
+
 // ============================================================================
 // Team Types
 // ============================================================================
 
 /// Team information
+///
+/// Compared and hashed by `team_id` only.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
     #[serde(rename = "team_id")]
     pub team_id: String,
     pub name: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
-    #[serde(rename = "cover_image")]
+    #[serde(
+        rename = "cover_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cover_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub leader: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<UserSimple>>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "chat_room_id")]
+    #[serde(rename = "chat_room_id", skip_serializing_if = "Option::is_none")]
     pub chat_room_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub twitter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub youtube: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub facebook: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub website: Option<String>,
-    #[serde(rename = "team_type")]
+    #[serde(rename = "team_type", skip_serializing_if = "Option::is_none")]
     pub team_type: Option<String>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// User simple
@@ -719,17 +2721,42 @@ pub struct UserSimple {
     #[serde(rename = "user_id")]
     pub user_id: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "membership_type")]
+    #[serde(rename = "membership_type", skip_serializing_if = "Option::is_none")]
     pub membership_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub memberships: Option<Vec<String>>,
-    #[serde(rename = "skill_level")]
+    #[serde(
+        rename = "skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub skill_level: Option<i64>,
 }
 
+impl UserSimple {
+    /// Whether the user has a paid membership
+    ///
+    /// Checks both `memberships` and `membership_type`.
+    pub fn is_premium(&self) -> bool {
+        self.memberships
+            .iter()
+            .flatten()
+            .chain(&self.membership_type)
+            .any(|m| Membership::from(m.as_str()).is_premium())
+    }
+}
+
 /// Team stats
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamStats {
@@ -737,8 +2764,35 @@ pub struct TeamStats {
     pub team_id: String,
     #[serde(rename = "game_id")]
     pub game_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lifetime: Option<serde_json::Value>,
-    pub segments: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<StatsSegment>>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl TeamStats {
+    /// Get the stats segment with the given label (e.g. a map such as "de_mirage")
+    ///
+    /// Labels are compared case-insensitively.
+    pub fn segment(&self, label: &str) -> Option<&StatsSegment> {
+        self.segments.as_deref()?.iter().find(|segment| {
+            segment
+                .label
+                .as_deref()
+                .is_some_and(|segment_label| segment_label.eq_ignore_ascii_case(label))
+        })
+    }
+
+    /// Get all stats segments for the given game mode (e.g. "5v5")
+    pub fn segments_by_mode<'a>(&'a self, mode: &'a str) -> impl Iterator<Item = &'a StatsSegment> {
+        self.segments
+            .iter()
+            .flatten()
+            .filter(move |segment| segment.mode.as_deref() == Some(mode))
+    }
 }
 
 /// Team list
@@ -749,6 +2803,35 @@ pub struct TeamList {
     pub items: Vec<Team>,
 }
 
+impl TeamList {
+    /// Get the teams for one game (e.g., "cs2")
+    ///
+    /// Games are compared case-insensitively. Teams without a game are
+    /// excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use faceit::HttpClient;
+    /// # async fn example() -> Result<(), faceit::error::Error> {
+    /// let client = HttpClient::new();
+    /// let teams = client.get_player_teams("player-id", Some(0), Some(100)).await?;
+    /// let cs2_teams = teams.filter_by_game("cs2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter_by_game(&self, game: &str) -> Vec<&Team> {
+        self.items
+            .iter()
+            .filter(|team| {
+                team.game
+                    .as_deref()
+                    .is_some_and(|team_game| team_game.eq_ignore_ascii_case(game))
+            })
+            .collect()
+    }
+}
+
 // ============================================================================
 // Search Types
 // ============================================================================
@@ -759,10 +2842,19 @@ pub struct UserSearch {
     #[serde(rename = "player_id")]
     pub player_id: String,
     pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub games: Option<Vec<GameUserSearch>>,
 }
 
@@ -782,18 +2874,51 @@ pub struct UsersSearchList {
     pub items: Vec<UserSearch>,
 }
 
+/// A player suggested while typing a nickname
+///
+/// The subset of [`UserSearch`] needed to render an autocomplete entry.
+/// Returned by [`Client::suggest_players`](crate::http::Client::suggest_players).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerSuggestion {
+    pub player_id: String,
+    pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub avatar: Option<String>,
+}
+
+impl From<UserSearch> for PlayerSuggestion {
+    fn from(user: UserSearch) -> Self {
+        Self {
+            player_id: user.player_id,
+            nickname: user.nickname,
+            avatar: user.avatar,
+        }
+    }
+}
+
 /// Team search result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamSearch {
     #[serde(rename = "team_id")]
     pub team_id: String,
     pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game: Option<String>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "chat_room_id")]
+    #[serde(rename = "chat_room_id", skip_serializing_if = "Option::is_none")]
     pub chat_room_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub verified: Option<bool>,
 }
 
@@ -813,30 +2938,41 @@ pub struct CompetitionSearch {
     #[serde(rename = "competition_type")]
     pub competition_type: String,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
     #[serde(rename = "organizer_id")]
     pub organizer_id: String,
-    #[serde(rename = "organizer_name")]
+    #[serde(rename = "organizer_name", skip_serializing_if = "Option::is_none")]
     pub organizer_name: Option<String>,
-    #[serde(rename = "organizer_type")]
+    #[serde(rename = "organizer_type", skip_serializing_if = "Option::is_none")]
     pub organizer_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
-    #[serde(rename = "started_at")]
+    #[serde(rename = "started_at", skip_serializing_if = "Option::is_none")]
     pub started_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slots: Option<i64>,
-    #[serde(rename = "number_of_members")]
+    #[serde(rename = "number_of_members", skip_serializing_if = "Option::is_none")]
     pub number_of_members: Option<i64>,
-    #[serde(rename = "players_joined")]
+    #[serde(rename = "players_joined", skip_serializing_if = "Option::is_none")]
     pub players_joined: Option<i64>,
-    #[serde(rename = "players_checkedin")]
+    #[serde(rename = "players_checkedin", skip_serializing_if = "Option::is_none")]
     pub players_checkedin: Option<i64>,
-    #[serde(rename = "prize_type")]
+    #[serde(rename = "prize_type", skip_serializing_if = "Option::is_none")]
     pub prize_type: Option<String>,
-    #[serde(rename = "total_prize")]
+    #[serde(rename = "total_prize", skip_serializing_if = "Option::is_none")]
     pub total_prize: Option<String>,
 }
 
+impl CompetitionSearch {
+    /// Get the competition type as a typed value
+    pub fn competition_type_kind(&self) -> CompetitionType {
+        CompetitionType::from(self.competition_type.as_str())
+    }
+}
+
 /// Competitions search list
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompetitionsSearchList {
@@ -845,6 +2981,26 @@ pub struct CompetitionsSearchList {
     pub items: Vec<CompetitionSearch>,
 }
 
+/// Combined player, team and hub search results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub players: UsersSearchList,
+    pub teams: TeamsSearchList,
+    pub hubs: CompetitionsSearchList,
+}
+
+impl SearchResults {
+    /// Total number of results across all categories
+    pub fn len(&self) -> usize {
+        self.players.len() + self.teams.len() + self.hubs.len()
+    }
+
+    /// Whether no category returned any results
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 // ============================================================================
 // Ranking Types
 // ============================================================================
@@ -856,10 +3012,11 @@ pub struct GlobalRanking {
     pub player_id: String,
     pub nickname: String,
     pub position: i64,
-    #[serde(rename = "faceit_elo")]
+    #[serde(rename = "faceit_elo", deserialize_with = "string_or_number")]
     pub faceit_elo: i64,
-    #[serde(rename = "game_skill_level")]
+    #[serde(rename = "game_skill_level", deserialize_with = "string_or_number")]
     pub game_skill_level: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
 }
 
@@ -880,77 +3037,239 @@ pub struct PlayerGlobalRanking {
     pub items: Vec<GlobalRanking>,
 }
 
+// ============================================================================
+// Leaderboard Types
+// ============================================================================
+
+/// Leaderboard of a hub or championship
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leaderboard {
+    #[serde(rename = "leaderboard_id")]
+    pub leaderboard_id: String,
+    #[serde(rename = "leaderboard_name", skip_serializing_if = "Option::is_none")]
+    pub leaderboard_name: Option<String>,
+    #[serde(rename = "leaderboard_type", skip_serializing_if = "Option::is_none")]
+    pub leaderboard_type: Option<String>,
+    #[serde(rename = "competition_id", skip_serializing_if = "Option::is_none")]
+    pub competition_id: Option<String>,
+    #[serde(rename = "competition_type", skip_serializing_if = "Option::is_none")]
+    pub competition_type: Option<String>,
+    #[serde(rename = "game_id", skip_serializing_if = "Option::is_none")]
+    pub game_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<i64>,
+    #[serde(rename = "ranking_type", skip_serializing_if = "Option::is_none")]
+    pub ranking_type: Option<String>,
+    #[serde(rename = "min_matches", skip_serializing_if = "Option::is_none")]
+    pub min_matches: Option<i64>,
+    #[serde(rename = "points_per_win", skip_serializing_if = "Option::is_none")]
+    pub points_per_win: Option<i64>,
+    #[serde(rename = "points_per_loss", skip_serializing_if = "Option::is_none")]
+    pub points_per_loss: Option<i64>,
+    #[serde(rename = "points_per_draw", skip_serializing_if = "Option::is_none")]
+    pub points_per_draw: Option<i64>,
+    #[serde(rename = "starting_points", skip_serializing_if = "Option::is_none")]
+    pub starting_points: Option<i64>,
+    #[serde(rename = "start_date", skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<i64>,
+    #[serde(rename = "end_date", skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<i64>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Leaderboards list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardsList {
+    pub start: i64,
+    pub end: i64,
+    pub items: Vec<Leaderboard>,
+}
+
+/// Standings of a leaderboard
+///
+/// One page of entries, ordered by position, together with the leaderboard
+/// they belong to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardRanking {
+    pub leaderboard: Leaderboard,
+    pub start: i64,
+    pub end: i64,
+    pub items: Vec<LeaderboardEntry>,
+}
+
+/// A player's standing on a leaderboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub player: LeaderboardPlayer,
+    pub position: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub points: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub played: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub won: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lost: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draw: Option<i64>,
+    #[serde(rename = "win_rate", skip_serializing_if = "Option::is_none")]
+    pub win_rate: Option<f64>,
+    #[serde(rename = "current_streak", skip_serializing_if = "Option::is_none")]
+    pub current_streak: Option<i64>,
+}
+
+/// Player shown on a leaderboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardPlayer {
+    #[serde(rename = "user_id")]
+    pub user_id: String,
+    pub nickname: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(
+        rename = "skill_level",
+        default,
+        deserialize_with = "option_string_or_number",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub skill_level: Option<i64>,
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
+    pub faceit_url: Option<String>,
+}
+
 // ============================================================================
 // Tournament Types
 // ============================================================================
 
 /// Tournament information
+///
+/// Older responses only carry the deprecated `competition_id`; it is used as
+/// `tournament_id` when that field is missing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Tournament {
     #[serde(rename = "tournament_id")]
     pub tournament_id: String,
-    #[serde(rename = "competition_id")]
+    #[serde(rename = "competition_id", skip_serializing_if = "Option::is_none")]
     pub competition_id: Option<String>, // Deprecated
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(rename = "game_id")]
     pub game_id: String,
-    #[serde(rename = "game_data")]
+    #[serde(rename = "game_data", skip_serializing_if = "Option::is_none")]
     pub game_data: Option<Game>,
     #[serde(rename = "organizer_id")]
     pub organizer_id: String,
-    #[serde(rename = "organizer_data")]
+    #[serde(rename = "organizer_data", skip_serializing_if = "Option::is_none")]
     pub organizer_data: Option<Organizer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
     pub status: String,
-    #[serde(rename = "started_at")]
+    #[serde(rename = "started_at", skip_serializing_if = "Option::is_none")]
     pub started_at: Option<i64>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "cover_image")]
+    #[serde(
+        rename = "cover_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cover_image: Option<String>,
-    #[serde(rename = "featured_image")]
+    #[serde(
+        rename = "featured_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub featured_image: Option<String>,
-    #[serde(rename = "anticheat_required")]
+    #[serde(rename = "anticheat_required", skip_serializing_if = "Option::is_none")]
     pub anticheat_required: Option<bool>,
-    #[serde(rename = "calculate_elo")]
+    #[serde(rename = "calculate_elo", skip_serializing_if = "Option::is_none")]
     pub calculate_elo: Option<bool>,
-    #[serde(rename = "best_of")]
+    #[serde(rename = "best_of", skip_serializing_if = "Option::is_none")]
     pub best_of: Option<i64>,
-    #[serde(rename = "match_type")]
+    #[serde(rename = "match_type", skip_serializing_if = "Option::is_none")]
     pub match_type: Option<String>,
-    #[serde(rename = "invite_type")]
+    #[serde(rename = "invite_type", skip_serializing_if = "Option::is_none")]
     pub invite_type: Option<String>,
-    #[serde(rename = "membership_type")]
+    #[serde(rename = "membership_type", skip_serializing_if = "Option::is_none")]
     pub membership_type: Option<String>,
-    #[serde(rename = "min_skill")]
+    #[serde(rename = "min_skill", skip_serializing_if = "Option::is_none")]
     pub min_skill: Option<i64>,
-    #[serde(rename = "max_skill")]
+    #[serde(rename = "max_skill", skip_serializing_if = "Option::is_none")]
     pub max_skill: Option<i64>,
-    #[serde(rename = "number_of_players")]
+    #[serde(rename = "number_of_players", skip_serializing_if = "Option::is_none")]
     pub number_of_players: Option<i64>,
-    #[serde(rename = "number_of_players_joined")]
+    #[serde(
+        rename = "number_of_players_joined",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub number_of_players_joined: Option<i64>,
-    #[serde(rename = "number_of_players_checkedin")]
+    #[serde(
+        rename = "number_of_players_checkedin",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub number_of_players_checkedin: Option<i64>,
-    #[serde(rename = "number_of_players_participants")]
+    #[serde(
+        rename = "number_of_players_participants",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub number_of_players_participants: Option<i64>,
-    #[serde(rename = "team_size")]
+    #[serde(rename = "team_size", skip_serializing_if = "Option::is_none")]
     pub team_size: Option<i64>,
-    #[serde(rename = "substitutes_allowed")]
+    #[serde(
+        rename = "substitutes_allowed",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub substitutes_allowed: Option<i64>,
-    #[serde(rename = "substitutions_allowed")]
+    #[serde(
+        rename = "substitutions_allowed",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub substitutions_allowed: Option<i64>,
-    #[serde(rename = "total_prize")]
+    #[serde(rename = "total_prize", skip_serializing_if = "Option::is_none")]
     pub total_prize: Option<String>,
-    #[serde(rename = "prize_type")]
+    #[serde(rename = "prize_type", skip_serializing_if = "Option::is_none")]
     pub prize_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rounds: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub voting: Option<serde_json::Value>,
-    #[serde(rename = "whitelist_countries")]
+    #[serde(
+        rename = "whitelist_countries",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub whitelist_countries: Option<Vec<String>>,
+    /// Fields not modelled by this crate, see [`extra`](Self::extra)
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Tournament {
+    /// Get the status as a typed value
+    pub fn status_kind(&self) -> ChampionshipStatus {
+        ChampionshipStatus::from(self.status.as_str())
+    }
 }
 
 /// Tournaments list
@@ -971,14 +3290,21 @@ pub struct Matchmaking {
     pub id: String,
     pub name: String,
     pub game: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
-    #[serde(rename = "short_description")]
+    #[serde(rename = "short_description", skip_serializing_if = "Option::is_none")]
     pub short_description: Option<String>,
-    #[serde(rename = "long_description")]
+    #[serde(rename = "long_description", skip_serializing_if = "Option::is_none")]
     pub long_description: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub icon: Option<String>,
-    #[serde(rename = "league_id")]
+    #[serde(rename = "league_id", skip_serializing_if = "Option::is_none")]
     pub league_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub queues: Option<Vec<MatchmakingQueue>>,
 }
 
@@ -987,9 +3313,11 @@ pub struct Matchmaking {
 pub struct MatchmakingQueue {
     pub id: String,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub open: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paused: Option<bool>,
-    #[serde(rename = "organizer_id")]
+    #[serde(rename = "organizer_id", skip_serializing_if = "Option::is_none")]
     pub organizer_id: Option<String>,
 }
 
@@ -1007,8 +3335,9 @@ pub struct MatchmakingSlim {
     pub id: String,
     pub name: String,
     pub game: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
-    #[serde(rename = "has_league")]
+    #[serde(rename = "has_league", skip_serializing_if = "Option::is_none")]
     pub has_league: Option<bool>,
 }
 
@@ -1020,45 +3349,1025 @@ pub struct TournamentSimple {
     pub name: String,
     #[serde(rename = "game_id")]
     pub game_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
     pub status: String,
-    #[serde(rename = "started_at")]
+    #[serde(rename = "started_at", skip_serializing_if = "Option::is_none")]
     pub started_at: Option<i64>,
-    #[serde(rename = "faceit_url")]
+    #[serde(rename = "faceit_url", skip_serializing_if = "Option::is_none")]
     pub faceit_url: Option<String>,
-    #[serde(rename = "featured_image")]
+    #[serde(
+        rename = "featured_image",
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub featured_image: Option<String>,
-    #[serde(rename = "anticheat_required")]
+    #[serde(rename = "anticheat_required", skip_serializing_if = "Option::is_none")]
     pub anticheat_required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<bool>,
-    #[serde(rename = "match_type")]
+    #[serde(rename = "match_type", skip_serializing_if = "Option::is_none")]
     pub match_type: Option<String>,
-    #[serde(rename = "invite_type")]
+    #[serde(rename = "invite_type", skip_serializing_if = "Option::is_none")]
     pub invite_type: Option<String>,
-    #[serde(rename = "membership_type")]
+    #[serde(rename = "membership_type", skip_serializing_if = "Option::is_none")]
     pub membership_type: Option<String>,
-    #[serde(rename = "min_skill")]
+    #[serde(rename = "min_skill", skip_serializing_if = "Option::is_none")]
     pub min_skill: Option<i64>,
-    #[serde(rename = "max_skill")]
+    #[serde(rename = "max_skill", skip_serializing_if = "Option::is_none")]
     pub max_skill: Option<i64>,
-    #[serde(rename = "number_of_players")]
+    #[serde(rename = "number_of_players", skip_serializing_if = "Option::is_none")]
     pub number_of_players: Option<i64>,
-    #[serde(rename = "number_of_players_joined")]
+    #[serde(
+        rename = "number_of_players_joined",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub number_of_players_joined: Option<i64>,
-    #[serde(rename = "number_of_players_checkedin")]
+    #[serde(
+        rename = "number_of_players_checkedin",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub number_of_players_checkedin: Option<i64>,
-    #[serde(rename = "number_of_players_participants")]
+    #[serde(
+        rename = "number_of_players_participants",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub number_of_players_participants: Option<i64>,
-    #[serde(rename = "team_size")]
+    #[serde(rename = "team_size", skip_serializing_if = "Option::is_none")]
     pub team_size: Option<i64>,
-    #[serde(rename = "total_prize")]
+    #[serde(rename = "total_prize", skip_serializing_if = "Option::is_none")]
     pub total_prize: Option<String>,
-    #[serde(rename = "prize_type")]
+    #[serde(rename = "prize_type", skip_serializing_if = "Option::is_none")]
     pub prize_type: Option<String>,
     #[serde(rename = "organizer_id")]
     pub organizer_id: String,
-    #[serde(rename = "subscriptions_count")]
+    #[serde(
+        rename = "subscriptions_count",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub subscriptions_count: Option<i64>,
-    #[serde(rename = "whitelist_countries")]
+    #[serde(
+        rename = "whitelist_countries",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub whitelist_countries: Option<Vec<String>>,
 }
+
+impl TournamentSimple {
+    /// Get the status as a typed value
+    pub fn status_kind(&self) -> ChampionshipStatus {
+        ChampionshipStatus::from(self.status.as_str())
+    }
+}
+
+// ============================================================================
+// League Types
+// ============================================================================
+
+/// League information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct League {
+    #[serde(rename = "league_id")]
+    pub league_id: String,
+    #[serde(rename = "game_id", skip_serializing_if = "Option::is_none")]
+    pub game_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(rename = "seasons_info", skip_serializing_if = "Option::is_none")]
+    pub seasons_info: Option<Vec<LeagueSeasonInfo>>,
+}
+
+/// Summary of a league season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeagueSeasonInfo {
+    #[serde(rename = "season_id")]
+    pub season_id: String,
+    #[serde(rename = "season_number", skip_serializing_if = "Option::is_none")]
+    pub season_number: Option<i64>,
+    #[serde(rename = "time_start", skip_serializing_if = "Option::is_none")]
+    pub time_start: Option<String>,
+    #[serde(rename = "time_end", skip_serializing_if = "Option::is_none")]
+    pub time_end: Option<String>,
+}
+
+/// League season details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeagueSeason {
+    #[serde(rename = "league_id", skip_serializing_if = "Option::is_none")]
+    pub league_id: Option<String>,
+    #[serde(rename = "season_id")]
+    pub season_id: String,
+    #[serde(rename = "season_number", skip_serializing_if = "Option::is_none")]
+    pub season_number: Option<i64>,
+    #[serde(rename = "time_start", skip_serializing_if = "Option::is_none")]
+    pub time_start: Option<String>,
+    #[serde(rename = "time_end", skip_serializing_if = "Option::is_none")]
+    pub time_end: Option<String>,
+    #[serde(
+        rename = "placement_matches_count",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub placement_matches_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divisions: Option<Vec<LeagueDivision>>,
+}
+
+/// League division
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeagueDivision {
+    #[serde(rename = "division_id")]
+    pub division_id: String,
+    #[serde(rename = "division_name", skip_serializing_if = "Option::is_none")]
+    pub division_name: Option<String>,
+    #[serde(rename = "division_type", skip_serializing_if = "Option::is_none")]
+    pub division_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<LeagueTier>>,
+}
+
+/// League division tier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeagueTier {
+    #[serde(rename = "tier_id")]
+    pub tier_id: String,
+    #[serde(rename = "tier_name", skip_serializing_if = "Option::is_none")]
+    pub tier_name: Option<String>,
+    #[serde(rename = "min_points", skip_serializing_if = "Option::is_none")]
+    pub min_points: Option<i64>,
+    #[serde(rename = "max_points", skip_serializing_if = "Option::is_none")]
+    pub max_points: Option<i64>,
+}
+
+/// A player's standing in a league season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerLeagueSeason {
+    #[serde(rename = "player_id", skip_serializing_if = "Option::is_none")]
+    pub player_id: Option<String>,
+    #[serde(rename = "league_id", skip_serializing_if = "Option::is_none")]
+    pub league_id: Option<String>,
+    #[serde(rename = "season_id", skip_serializing_if = "Option::is_none")]
+    pub season_id: Option<String>,
+    #[serde(rename = "division_id", skip_serializing_if = "Option::is_none")]
+    pub division_id: Option<String>,
+    #[serde(rename = "tier_id", skip_serializing_if = "Option::is_none")]
+    pub tier_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub points: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(rename = "placement_matches", skip_serializing_if = "Option::is_none")]
+    pub placement_matches: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYER_FIXTURE: &str = r#"{
+        "player_id": "5ea07280-2399-4c7e-88ab-f2f7db0c449f",
+        "nickname": "s1mple",
+        "avatar": "https://assets.faceit-cdn.net/avatars/avatar.jpg",
+        "country": "ua",
+        "faceit_url": "https://www.faceit.com/{lang}/players/s1mple",
+        "steam_id_64": "76561198034202275",
+        "memberships": ["free"],
+        "games": {
+            "cs2": {
+                "faceit_elo": 3500,
+                "game_player_id": "76561198034202275",
+                "game_player_name": "s1mple",
+                "region": "EU",
+                "skill_level": 10,
+                "skill_level_label": "10"
+            }
+        },
+        "verified": true,
+        "activated_at": "2015-06-16T10:47:04Z",
+        "settings": {"language": "en"}
+    }"#;
+
+    const MATCH_FIXTURE: &str = r#"{
+        "match_id": "1-8f3c2e8a-5b0f-4a53-9f5e-2c1f0b2d6e11",
+        "game": "cs2",
+        "region": "EU",
+        "competition_id": "hub-id",
+        "competition_type": "hub",
+        "competition_name": "Weekly Hub",
+        "organizer_id": "organizer-id",
+        "teams": {
+            "faction1": {
+                "faction_id": "faction-1",
+                "leader": "player-1",
+                "name": "team_player-1",
+                "type": "",
+                "roster": [
+                    {"player_id": "player-1", "nickname": "alpha", "game_skill_level": 10, "membership": "premium"}
+                ],
+                "stats": {"rating": 2100, "skillLevel": {"average": 9, "range": {"min": 8, "max": 10}}, "winProbability": 0.52}
+            }
+        },
+        "status": "FINISHED",
+        "started_at": 1700000000,
+        "finished_at": 1700002400,
+        "best_of": 1,
+        "results": {"score": {"faction1": 1, "faction2": 0}, "winner": "faction1"},
+        "detailed_results": [{"asc_score": true, "factions": {"faction1": {"score": 1}}, "winner": "faction1"}],
+        "faceit_url": "https://www.faceit.com/{lang}/cs2/room/1-8f3c2e8a",
+        "demo_url": ["https://demos.faceit-cdn.net/1.dem.gz"],
+        "calculate_elo": true,
+        "version": 3,
+        "voting": {"map": {"pick": ["de_mirage"]}}
+    }"#;
+
+    const BAN_FIXTURE: &str = r#"{
+        "user_id": "player-1",
+        "nickname": "alpha",
+        "game": "cs2",
+        "starts_at": "2024-01-01T00:00:00Z",
+        "ends_at": "2024-01-08T00:00:00Z",
+        "type": "matchmaking",
+        "reason": "Leaving matches"
+    }"#;
+
+    /// Deserialize `json`, serialize it again and check the result deserializes
+    /// to the same value, returning the re-serialized JSON
+    fn assert_round_trip<T>(json: &str) -> serde_json::Value
+    where
+        T: serde::de::DeserializeOwned + Serialize,
+    {
+        let parsed: T = parse_response(json).unwrap();
+        let serialized = serde_json::to_string(&parsed).unwrap();
+        let reparsed: T = parse_response(&serialized).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&reparsed).unwrap()
+        );
+        serde_json::from_str(&serialized).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_player() {
+        let value = assert_round_trip::<Player>(PLAYER_FIXTURE);
+        assert_eq!(value["player_id"], "5ea07280-2399-4c7e-88ab-f2f7db0c449f");
+        assert_eq!(value["games"]["cs2"]["faceit_elo"], 3500);
+    }
+
+    #[test]
+    fn test_round_trip_match() {
+        let value = assert_round_trip::<Match>(MATCH_FIXTURE);
+        let faction = &value["teams"]["faction1"];
+        assert_eq!(faction["type"], "");
+        assert_eq!(faction["stats"]["skillLevel"]["range"]["max"], 10);
+        assert_eq!(faction["stats"]["winProbability"], 0.52);
+        assert_eq!(value["voting"]["map"]["pick"][0], "de_mirage");
+    }
+
+    #[test]
+    fn test_round_trip_preserves_renamed_fields() {
+        let value = assert_round_trip::<PlayerBan>(BAN_FIXTURE);
+        assert_eq!(value["type"], "matchmaking");
+        assert!(value.get("ban_type").is_none());
+    }
+
+    #[test]
+    fn test_match_duration() {
+        let finished: Match = parse_response(MATCH_FIXTURE).unwrap();
+        assert_eq!(
+            finished.duration(),
+            Some(std::time::Duration::from_secs(2400))
+        );
+
+        let ongoing: Match = parse_response(
+            r#"{"match_id": "match-id", "game": "cs2", "status": "ONGOING", "started_at": 1700000000}"#,
+        )
+        .unwrap();
+        assert_eq!(ongoing.duration(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        assert_eq!(
+            player.to_string(),
+            "s1mple (5ea07280-2399-4c7e-88ab-f2f7db0c449f)"
+        );
+        let match_data: Match = parse_response(MATCH_FIXTURE).unwrap();
+        assert_eq!(
+            match_data.to_string(),
+            format!("{} [{}]", match_data.match_id, match_data.status)
+        );
+    }
+
+    #[test]
+    fn test_identity_eq() {
+        let player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        let mut renamed = player.clone();
+        renamed.nickname = "s1mple2".to_string();
+        assert_eq!(player, renamed);
+
+        let players: std::collections::HashSet<Player> = [player, renamed].into_iter().collect();
+        assert_eq!(players.len(), 1);
+    }
+
+    #[test]
+    fn test_match_stats_player_totals() {
+        let stats: MatchStats = parse_response(
+            r#"{"rounds": [
+                {"teams": [{"players": [
+                    {"player_id": "p-1", "nickname": "alpha", "player_stats": {"Kills": "20", "ADR": "80.5", "Result": "1"}}
+                ]}]},
+                {"teams": [{"players": [
+                    {"player_id": "p-1", "nickname": "alpha", "player_stats": {"Kills": 10, "ADR": "99.5", "MVPs": "n/a"}}
+                ]}]}
+            ]}"#,
+        )
+        .unwrap();
+
+        let totals = stats.player_totals();
+        let alpha = &totals["p-1"];
+        assert_eq!(alpha.nickname.as_deref(), Some("alpha"));
+        assert_eq!(alpha.maps, 2);
+        assert_eq!(alpha.total("Kills"), Some(30.0));
+        assert_eq!(alpha.average("ADR"), Some(90.0));
+        assert_eq!(alpha.total("MVPs"), None);
+    }
+
+    #[test]
+    fn test_extra_fields_preserved() {
+        let json = r#"{"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2", "new_field": {"a": 1}}"#;
+        let game: Game = parse_response(json).unwrap();
+        assert_eq!(game.extra("new_field"), Some(&serde_json::json!({"a": 1})));
+        assert!(game.extra("game_id").is_none());
+
+        let value = assert_round_trip::<Game>(json);
+        assert_eq!(value["new_field"]["a"], 1);
+    }
+
+    #[test]
+    fn test_match_roster() {
+        let match_data: Match = parse_response(MATCH_FIXTURE).unwrap();
+        let ids: Vec<&str> = match_data
+            .roster()
+            .iter()
+            .map(|member| member.player_id.as_str())
+            .collect();
+        assert_eq!(ids, ["player-1"]);
+    }
+
+    #[test]
+    fn test_serialize_skips_none_fields() {
+        let value = assert_round_trip::<Match>(
+            r#"{"match_id": "match-id", "game": "cs2", "status": "READY", "region": null}"#,
+        );
+        assert_eq!(
+            value,
+            serde_json::json!({"match_id": "match-id", "game": "cs2", "status": "READY"})
+        );
+    }
+
+    #[test]
+    fn test_parse_response_player_fixture() {
+        let player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        assert_eq!(player.nickname, "s1mple");
+        assert_eq!(player.games.unwrap()["cs2"].faceit_elo, Some(3500));
+    }
+
+    #[test]
+    fn test_player_stats_segment_lookup() {
+        let stats: PlayerStats = parse_response(
+            r#"{
+                "player_id": "player-id",
+                "game_id": "cs2",
+                "lifetime": {"Matches": "120"},
+                "segments": [
+                    {"label": "de_mirage", "mode": "5v5", "type": "Map", "stats": {"Kills": "420"}},
+                    {"label": "de_inferno", "mode": "5v5", "type": "Map", "stats": {"Kills": "310"}},
+                    {"label": "de_dust2", "mode": "2v2", "type": "Map", "stats": {"Kills": "12"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mirage = stats.segment("De_Mirage").unwrap();
+        assert_eq!(mirage.stats.as_ref().unwrap()["Kills"], "420");
+        assert!(stats.segment("de_nuke").is_none());
+        assert_eq!(stats.segments_by_mode("5v5").count(), 2);
+    }
+
+    #[test]
+    fn test_team_stats_segments() {
+        let stats: TeamStats = parse_response(
+            r#"{
+                "team_id": "team-id",
+                "game_id": "cs2",
+                "segments": [
+                    {"mode": "5v5", "type": "Overview", "stats": {"Matches": "40"}},
+                    {"label": "de_mirage", "mode": "5v5", "type": "Map", "stats": {"Matches": "25"}},
+                    {"label": "de_dust2", "mode": "2v2", "type": "Map", "stats": {"Matches": "3"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(stats.segments.as_ref().unwrap()[0].label.is_none());
+        assert_eq!(
+            stats.segment("DE_MIRAGE").unwrap().stats.as_ref().unwrap()["Matches"],
+            "25"
+        );
+        assert_eq!(stats.segments_by_mode("5v5").count(), 2);
+        assert_eq!(stats.segments_by_mode("1v1").count(), 0);
+    }
+
+    #[test]
+    fn test_championship_prizes() {
+        let championship: Championship = parse_response(
+            r#"{
+                "championship_id": "championship-id",
+                "name": "Cup",
+                "game_id": "cs2",
+                "organizer_id": "organizer-id",
+                "status": "finished",
+                "prizes": [
+                    {"rank": 2, "faceit_points": 500},
+                    {"rank": 1, "faceit_points": 1000},
+                    {"rank": 3}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(championship.total_faceit_points(), 1500);
+        assert_eq!(
+            championship.prize_for_rank(2).unwrap().faceit_points,
+            Some(500)
+        );
+        assert!(championship.prize_for_rank(4).is_none());
+        let top: Vec<i64> = championship.top_prizes(2).iter().map(|p| p.rank).collect();
+        assert_eq!(top, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_legacy_id_fields() {
+        let archived: Championship = parse_response(
+            r#"{"id": "old-id", "name": "Cup", "game_id": "cs2", "organizer_id": "org", "status": "finished"}"#,
+        )
+        .unwrap();
+        assert_eq!(archived.championship_id, "old-id");
+
+        let current: Championship = parse_response(
+            r#"{"championship_id": "new-id", "id": "old-id", "name": "Cup", "game_id": "cs2", "organizer_id": "org", "status": "finished"}"#,
+        )
+        .unwrap();
+        assert_eq!(current.championship_id, "new-id");
+        assert_eq!(current.id.as_deref(), Some("old-id"));
+
+        let round_trip: Championship =
+            serde_json::from_value(serde_json::to_value(&current).unwrap()).unwrap();
+        assert_eq!(round_trip.championship_id, "new-id");
+
+        let tournament: Tournament = parse_response(
+            r#"{"competition_id": "old-id", "name": "Cup", "game_id": "cs2", "organizer_id": "org", "status": "finished"}"#,
+        )
+        .unwrap();
+        assert_eq!(tournament.tournament_id, "old-id");
+    }
+
+    #[test]
+    fn test_empty_image_urls_are_none() {
+        let player: Player = parse_response(
+            r#"{"player_id": "p-1", "nickname": "s1mple", "avatar": "", "cover_image": ""}"#,
+        )
+        .unwrap();
+        assert!(player.avatar.is_none());
+        assert!(player.cover_image.is_none());
+
+        let team: Team = parse_response(
+            r#"{"team_id": "t-1", "name": "Team", "nickname": "team", "avatar": "https://example.com/a.png"}"#,
+        )
+        .unwrap();
+        assert_eq!(team.avatar.as_deref(), Some("https://example.com/a.png"));
+
+        let assets: GameAssets = parse_response(
+            r#"{"cover": "", "featured_img_m": "", "flag_img_s": "https://example.com/f.png"}"#,
+        )
+        .unwrap();
+        assert!(assets.cover.is_none());
+        assert_eq!(assets.featured(ImageSize::Medium), None);
+        assert_eq!(
+            assets.flag(ImageSize::Small),
+            Some("https://example.com/f.png")
+        );
+
+        let entity: VotingEntity =
+            parse_response(r#"{"name": "Mirage", "image_sm": "", "image_lg": ""}"#).unwrap();
+        assert!(entity.image_sm.is_none());
+        assert!(entity.image_lg.is_none());
+
+        let matchmaking: Matchmaking =
+            parse_response(r#"{"id": "mm-1", "name": "5v5", "game": "cs2", "icon": ""}"#).unwrap();
+        assert!(matchmaking.icon.is_none());
+    }
+
+    #[test]
+    fn test_game_id_aliases() {
+        assert_eq!(GameId::from("cs:go"), GameId::Csgo);
+        assert!(GameId::Csgo.is_deprecated());
+        assert_eq!(GameId::from("csgo").current(), GameId::Cs2);
+        assert_eq!(GameId::from("cs2").current().as_str(), "cs2");
+        assert_eq!(
+            GameId::from("valorant"),
+            GameId::Unknown("valorant".to_string())
+        );
+        assert_eq!(serde_json::to_value(GameId::Dota2).unwrap(), "dota2");
+    }
+
+    #[test]
+    fn test_championship_schedule() {
+        let championship: Championship = parse_response(
+            r#"{
+                "championship_id": "championship-id",
+                "name": "Cup",
+                "game_id": "cs2",
+                "organizer_id": "organizer-id",
+                "status": "started",
+                "schedule": {
+                    "final": {"date": 300, "status": "scheduled"},
+                    "groups": {"date": 100, "status": "finished"},
+                    "playoffs": {"date": 200, "status": "scheduled"}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let names: Vec<&str> = championship
+            .schedule_sorted()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["groups", "playoffs", "final"]);
+        assert_eq!(
+            championship.next_phase(150).map(|(name, _)| name),
+            Some("playoffs")
+        );
+        assert!(championship.next_phase(300).is_none());
+        assert_eq!(championship.status_kind(), ChampionshipStatus::Started);
+        assert!(championship.seeding_strategy_kind().is_none());
+    }
+
+    #[test]
+    fn test_championship_enums() {
+        assert_eq!(
+            ChampionshipStatus::from("CHECKING_IN"),
+            ChampionshipStatus::CheckingIn
+        );
+        assert!(ChampionshipStatus::from("cancelled").is_over());
+        assert!(!ChampionshipStatus::Join.is_over());
+        assert_eq!(SeedingStrategy::from("Random"), SeedingStrategy::Random);
+        assert_eq!(
+            SeedingStrategy::from("swiss"),
+            SeedingStrategy::Unknown("swiss".to_string())
+        );
+    }
+
+    #[test]
+    fn test_championship_registration_open() {
+        let mut championship: Championship = parse_response(
+            r#"{
+                "championship_id": "championship-id",
+                "name": "Cup",
+                "game_id": "cs2",
+                "organizer_id": "organizer-id",
+                "status": "join",
+                "subscription_start": 1700000000000,
+                "subscription_end": 1700086400000,
+                "subscriptions_locked": false,
+                "full": false,
+                "current_subscriptions": 15,
+                "slots": 16
+            }"#,
+        )
+        .unwrap();
+        let at = |secs| DateTime::from_timestamp(secs, 0).unwrap();
+
+        assert!(!championship.registration_open(at(1_699_999_999)));
+        assert!(championship.registration_open(at(1_700_000_000)));
+        assert!(!championship.registration_open(at(1_700_086_400)));
+
+        // Same window in seconds
+        championship.subscription_start = Some(1_700_000_000);
+        championship.subscription_end = Some(1_700_086_400);
+        assert!(championship.registration_open(at(1_700_050_000)));
+
+        championship.current_subscriptions = Some(16);
+        assert!(!championship.registration_open(at(1_700_050_000)));
+        championship.current_subscriptions = Some(15);
+        championship.subscriptions_locked = Some(true);
+        assert!(!championship.registration_open(at(1_700_050_000)));
+    }
+
+    #[test]
+    fn test_championship_checkin() {
+        let mut championship: Championship = parse_response(
+            r#"{
+                "championship_id": "championship-id",
+                "name": "Cup",
+                "game_id": "cs2",
+                "organizer_id": "organizer-id",
+                "status": "checking_in",
+                "checkin_enabled": true,
+                "checkin_start": 1700000000000,
+                "checkin_clear": 1700001800000,
+                "championship_start": 1700003600000
+            }"#,
+        )
+        .unwrap();
+        let at = |secs| DateTime::from_timestamp(secs, 0).unwrap();
+
+        assert!(!championship.checkin_open(at(1_699_999_999)));
+        assert!(championship.checkin_open(at(1_700_000_000)));
+        assert!(!championship.checkin_open(at(1_700_001_800)));
+        assert_eq!(
+            championship.time_until_checkin(at(1_699_999_400)),
+            Some(std::time::Duration::from_secs(600))
+        );
+        assert!(championship.time_until_checkin(at(1_700_000_000)).is_none());
+
+        // Without a clear time, check-in closes when the championship starts
+        championship.checkin_clear = None;
+        assert!(championship.checkin_open(at(1_700_002_000)));
+        assert!(!championship.checkin_open(at(1_700_003_600)));
+
+        championship.checkin_enabled = Some(false);
+        assert!(!championship.checkin_open(at(1_700_000_000)));
+        assert!(championship.time_until_checkin(at(1_699_999_400)).is_none());
+    }
+
+    #[test]
+    fn test_games_sorted_by_order() {
+        let games: GamesList = parse_response(
+            r#"{"start": 0, "end": 4, "items": [
+                {"game_id": "dota2", "short_label": "Dota 2", "long_label": "Dota 2", "order": 2},
+                {"game_id": "lol", "short_label": "LoL", "long_label": "League of Legends"},
+                {"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2", "order": 1},
+                {"game_id": "tf2", "short_label": "TF2", "long_label": "Team Fortress 2", "order": 2}
+            ]}"#,
+        )
+        .unwrap();
+
+        let ids: Vec<&str> = games
+            .sorted_by_order()
+            .iter()
+            .map(|game| game.game_id.as_str())
+            .collect();
+        assert_eq!(ids, ["cs2", "dota2", "tf2", "lol"]);
+    }
+
+    #[test]
+    fn test_list_iteration() {
+        let list: GamesList = parse_response(
+            r#"{"start": 0, "end": 2, "items": [
+                {"game_id": "cs2", "short_label": "CS2", "long_label": "Counter-Strike 2"},
+                {"game_id": "dota2", "short_label": "Dota 2", "long_label": "Dota 2"}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+        let ids: Vec<&str> = list.iter().map(|game| game.game_id.as_str()).collect();
+        assert_eq!(ids, ["cs2", "dota2"]);
+        let mut count = 0;
+        for _game in &list {
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        let owned: Vec<Game> = list.into_iter().collect();
+        assert_eq!(owned[1].game_id, "dota2");
+    }
+
+    #[test]
+    fn test_history_filter_by_type() {
+        let history: MatchHistoryList = parse_response(
+            r#"{"start": 0, "end": 3, "items": [
+                {"match_id": "m-1", "game_id": "cs2", "competition_type": "matchmaking", "status": "FINISHED"},
+                {"match_id": "m-2", "game_id": "cs2", "competition_type": "hub", "status": "FINISHED"},
+                {"match_id": "m-3", "game_id": "cs2", "status": "FINISHED"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let ranked: Vec<&str> = history
+            .filter_by_type("Matchmaking")
+            .iter()
+            .map(|h| h.match_id.as_str())
+            .collect();
+        assert_eq!(ranked, ["m-1"]);
+        assert_eq!(history.filter_by_type(CompetitionType::Hub).len(), 1);
+        assert_eq!(
+            history.items[1].competition_type_kind(),
+            Some(CompetitionType::Hub)
+        );
+        assert_eq!(history.items[2].competition_type_kind(), None);
+    }
+
+    #[test]
+    fn test_player_game_detail() {
+        let player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        assert_eq!(player.game_detail("cs2").unwrap().faceit_elo, Some(3500));
+        assert!(player.game_detail("dota2").is_none());
+    }
+
+    #[test]
+    fn test_hub_user_resolve_roles() {
+        let roles: HubRolesList = parse_response(
+            r#"{"start": 0, "end": 2, "items": [
+                {"role_id": "r-admin", "name": "Admin", "ranking": 100},
+                {"role_id": "r-mod", "name": "Moderator", "ranking": 50}
+            ]}"#,
+        )
+        .unwrap();
+        let member: HubUser = parse_response(
+            r#"{"user_id": "u-1", "nickname": "alpha", "roles": ["r-mod", "r-gone", "r-admin"]}"#,
+        )
+        .unwrap();
+
+        let names: Vec<String> = member
+            .resolve_roles(&roles.items)
+            .into_iter()
+            .map(|role| role.name)
+            .collect();
+        assert_eq!(names, ["Moderator", "Admin"]);
+    }
+
+    #[test]
+    fn test_membership() {
+        assert_eq!(Membership::from("PREMIUM"), Membership::Premium);
+        assert!(Membership::Plus.is_premium());
+        assert!(!Membership::Free.is_premium());
+        assert!(!Membership::from("cs2").is_premium());
+
+        let player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        assert_eq!(player.membership_kinds(), [Membership::Free]);
+        assert!(!player.is_premium());
+
+        let roster: Roster =
+            parse_response(r#"{"player_id": "p-1", "nickname": "alpha", "membership": "premium"}"#)
+                .unwrap();
+        assert_eq!(roster.membership_kind(), Some(Membership::Premium));
+        assert!(roster.is_premium());
+    }
+
+    #[test]
+    fn test_string_or_number() {
+        let ranking: GlobalRanking = parse_response(
+            r#"{"player_id": "p-1", "nickname": "alpha", "position": 1, "faceit_elo": "4000", "game_skill_level": 10}"#,
+        )
+        .unwrap();
+        assert_eq!(ranking.faceit_elo, 4000);
+        assert_eq!(ranking.game_skill_level, 10);
+
+        let detail: GameDetail = parse_response(r#"{"skill_level": "7"}"#).unwrap();
+        assert_eq!(detail.skill_level, Some(7));
+        assert_eq!(detail.faceit_elo, None);
+
+        assert!(parse_response::<GameDetail>(r#"{"skill_level": "high"}"#).is_err());
+    }
+
+    #[test]
+    fn test_history_player_won() {
+        let history: MatchHistory = parse_response(
+            r#"{"match_id": "m-1", "game_id": "cs2", "status": "FINISHED",
+                "results": {"winner": "faction2", "score": {"faction1": 10, "faction2": 13}},
+                "teams": {
+                    "faction1": {"players": [{"player_id": "p-1", "nickname": "alpha"}]},
+                    "faction2": {"players": [{"player_id": "p-2", "nickname": "beta"}]}
+                }}"#,
+        )
+        .unwrap();
+
+        assert_eq!(history.player_won("p-1"), Some(false));
+        assert_eq!(history.player_won("p-2"), Some(true));
+        assert_eq!(history.player_won("p-3"), None);
+    }
+
+    #[test]
+    fn test_match_status() {
+        assert_eq!(MatchStatus::from("FINISHED"), MatchStatus::Finished);
+        assert_eq!(MatchStatus::from("ongoing"), MatchStatus::Ongoing);
+        assert_eq!(MatchStatus::Finished.as_str(), "FINISHED");
+        assert!(MatchStatus::Cancelled.is_terminal());
+        assert!(!MatchStatus::Ready.is_terminal());
+    }
+
+    #[test]
+    fn test_player_ban_remaining() {
+        let ban: PlayerBan = parse_response(BAN_FIXTURE).unwrap();
+        let during = "2024-01-05T00:00:00Z".parse().unwrap();
+        let after = "2024-01-09T00:00:00Z".parse().unwrap();
+
+        assert!(ban.is_active(during));
+        assert!(!ban.is_active(after));
+        assert_eq!(
+            ban.remaining(during),
+            Some(std::time::Duration::from_secs(3 * 24 * 60 * 60))
+        );
+        assert_eq!(ban.remaining(after), None);
+
+        let permanent = PlayerBan {
+            ends_at: "9999-12-31T23:59:59Z".parse().unwrap(),
+            ..ban
+        };
+        assert!(permanent.is_permanent());
+        assert!(permanent.is_active(after));
+        assert_eq!(permanent.remaining(after), None);
+    }
+
+    #[test]
+    fn test_parsed_voting() {
+        let mut match_data: Match = parse_response(MATCH_FIXTURE).unwrap();
+        let voting = match_data.parsed_voting().unwrap().unwrap();
+        assert_eq!(voting.map_picks(), ["de_mirage"]);
+
+        match_data.voting = Some(serde_json::json!({
+            "voted_entity_types": ["map", "location"],
+            "map": {
+                "entities": [
+                    {"guid": "de_inferno", "name": "Inferno"},
+                    {"guid": "de_nuke", "name": "Nuke"}
+                ],
+                "pick": ["de_nuke"]
+            },
+            "location": {"pick": ["Frankfurt"]}
+        }));
+        let voting = match_data.parsed_voting().unwrap().unwrap();
+        assert_eq!(voting.voted_entity_types, ["map", "location"]);
+        let picked = voting.category("map").unwrap().picked_entities();
+        assert_eq!(picked[0].name.as_deref(), Some("Nuke"));
+        assert_eq!(voting.category("location").unwrap().pick, ["Frankfurt"]);
+
+        match_data.voting = None;
+        assert!(match_data.parsed_voting().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_join_permission() {
+        assert_eq!(JoinPermission::from("PUBLIC"), JoinPermission::Public);
+        assert_eq!(
+            JoinPermission::from("invite_only"),
+            JoinPermission::InviteOnly
+        );
+        assert_eq!(
+            JoinPermission::from("open"),
+            JoinPermission::Unknown("open".to_string())
+        );
+        assert_eq!(
+            JoinPermission::from("Members_Only"),
+            JoinPermission::Unknown("Members_Only".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_response_invalid_body() {
+        let result = parse_response::<Player>("<html>Bad Gateway</html>");
+        assert!(matches!(result, Err(Error::Json(_))));
+    }
+
+    #[test]
+    fn test_stat_map() {
+        let round: RoundStats = serde_json::from_str(
+            r#"{
+                "round_stats": {"Map": "de_inferno", "Rounds": "24"},
+                "teams": [{
+                    "team_stats": {"Final Score": "13", "Team Win": "1", "Team Headshots": "4.5"},
+                    "players": [{"player_stats": {"Kills": 20, "MVPs": "n/a", "Result": "0"}}]
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(round.get_str("Map"), Some("de_inferno"));
+        assert_eq!(round.get_i64("Rounds"), Some(24));
+
+        let team = &round.teams.as_ref().unwrap()[0];
+        assert_eq!(team.get_i64("Final Score"), Some(13));
+        assert_eq!(team.get_bool("Team Win"), Some(true));
+        assert_eq!(team.get_f64("Team Headshots"), Some(4.5));
+        assert_eq!(team.get_i64("Team Headshots"), None);
+
+        let player = &team.players.as_ref().unwrap()[0];
+        assert_eq!(player.get_f64("Kills"), Some(20.0));
+        assert_eq!(player.get_str("Kills"), None);
+        assert_eq!(player.get_i64("MVPs"), None);
+        assert_eq!(player.get_bool("Result"), Some(false));
+        assert_eq!(player.get_f64("Deaths"), None);
+    }
+
+    #[test]
+    fn test_web_urls() {
+        let mut player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        assert_eq!(
+            player.web_url("en"),
+            "https://www.faceit.com/en/players/s1mple"
+        );
+        player.faceit_url = None;
+        assert_eq!(
+            player.web_url("de"),
+            "https://www.faceit.com/de/players/s1mple"
+        );
+
+        let mut match_data: Match = parse_response(MATCH_FIXTURE).unwrap();
+        assert_eq!(
+            match_data.web_url("en"),
+            "https://www.faceit.com/en/cs2/room/1-8f3c2e8a"
+        );
+        match_data.faceit_url = None;
+        assert_eq!(
+            match_data.web_url("pt"),
+            format!("https://www.faceit.com/pt/cs2/room/{}", match_data.match_id)
+        );
+    }
+
+    #[test]
+    fn test_team_list_filter_by_game() {
+        let teams: TeamList = parse_response(
+            r#"{"start": 0, "end": 3, "items": [
+                {"team_id": "t-1", "name": "Alpha", "nickname": "alpha", "game": "cs2"},
+                {"team_id": "t-2", "name": "Beta", "nickname": "beta", "game": "csgo"},
+                {"team_id": "t-3", "name": "Gamma", "nickname": "gamma"},
+                {"team_id": "t-4", "name": "Delta", "nickname": "delta", "game": "CS2"}
+            ]}"#,
+        )
+        .unwrap();
+        let ids: Vec<&str> = teams
+            .filter_by_game("cs2")
+            .iter()
+            .map(|team| team.team_id.as_str())
+            .collect();
+        assert_eq!(ids, ["t-1", "t-4"]);
+    }
+
+    #[test]
+    fn test_leaderboard_ranking() {
+        let ranking: LeaderboardRanking = parse_response(
+            r#"{
+                "leaderboard": {"leaderboard_id": "l-1", "leaderboard_name": "Season 3", "competition_id": "h-1", "season": 3, "points_per_win": 3},
+                "start": 0,
+                "end": 2,
+                "items": [
+                    {"player": {"user_id": "p-1", "nickname": "alpha", "skill_level": "10"}, "position": 1, "points": 42, "played": 20, "won": 14, "lost": 6, "win_rate": 0.7, "current_streak": 3},
+                    {"player": {"user_id": "p-2", "nickname": "beta"}, "position": 2, "points": 40}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(ranking.leaderboard.season, Some(3));
+        assert_eq!(ranking.len(), 2);
+        let leader = ranking.iter().next().unwrap();
+        assert_eq!(leader.player.nickname, "alpha");
+        assert_eq!(leader.player.skill_level, Some(10));
+        assert_eq!(leader.win_rate, Some(0.7));
+    }
+
+    #[test]
+    fn test_player_platform_ids() {
+        let player: Player = parse_response(PLAYER_FIXTURE).unwrap();
+        assert_eq!(player.steam_id(), Some("76561198034202275"));
+        assert!(player.platform("steam").is_none());
+
+        let player: Player = parse_response(
+            r#"{"player_id": "p-1", "nickname": "s1mple", "steam_id_64": "", "platforms": {"steam": "76561198034202275", "xbox": ""}}"#,
+        )
+        .unwrap();
+        assert_eq!(player.platform("steam"), Some("76561198034202275"));
+        assert!(player.platform("xbox").is_none());
+        assert_eq!(player.steam_id(), Some("76561198034202275"));
+    }
+
+    #[test]
+    fn test_matches_list_status_filters() {
+        let template: serde_json::Value = serde_json::from_str(MATCH_FIXTURE).unwrap();
+        let items: Vec<serde_json::Value> =
+            ["ONGOING", "PAUSED", "VOTING", "FINISHED", "CANCELLED"]
+                .iter()
+                .enumerate()
+                .map(|(i, status)| {
+                    let mut item = template.clone();
+                    item["match_id"] = format!("m-{}", i).into();
+                    item["status"] = (*status).into();
+                    item
+                })
+                .collect();
+        let list: MatchesList =
+            parse_response(&serde_json::json!({"start": 0, "end": 5, "items": items}).to_string())
+                .unwrap();
+        let ids = |matches: Vec<&Match>| -> Vec<String> {
+            matches.iter().map(|m| m.match_id.clone()).collect()
+        };
+
+        assert_eq!(ids(list.filter_status(MatchStatus::Ongoing)), ["m-0"]);
+        assert_eq!(ids(list.ongoing()), ["m-0", "m-1"]);
+        assert_eq!(ids(list.upcoming()), ["m-2"]);
+        assert_eq!(ids(list.finished()), ["m-3"]);
+    }
+}
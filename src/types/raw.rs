@@ -0,0 +1,81 @@
+//! Untyped JSON forms of the response types
+//!
+//! Fields the API returns as free-form JSON, such as
+//! [`PlayerStats::lifetime`](super::PlayerStats::lifetime),
+//! [`RoundStats::round_stats`](super::RoundStats::round_stats) and
+//! [`Match::voting`](super::Match::voting), are kept as
+//! [`serde_json::Value`]. Typed views of them are added as methods (e.g.
+//! [`Match::parsed_voting`](super::Match::parsed_voting) or
+//! [`StatMap`](super::StatMap)) and never replace the untyped field.
+//!
+//! Fields this crate does not model are kept in each type's `extra` map, so
+//! serializing a response type reproduces the JSON it was parsed from. The
+//! functions here do that, for custom parsing of fields that are typed, such
+//! as [`PlayerStats::segments`](super::PlayerStats::segments), or for
+//! passing a response on unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use faceit::types::{parse_response, raw, PlayerStats};
+//!
+//! let stats: PlayerStats = parse_response(
+//!     r#"{"player_id": "p-1", "game_id": "cs2", "segments": [{"label": "de_mirage", "new_field": 1}]}"#,
+//! )?;
+//! let segments = raw::field(&stats, "segments")?.unwrap();
+//! assert_eq!(segments[0]["new_field"], 1);
+//! # Ok::<(), faceit::error::Error>(())
+//! ```
+
+use crate::error::Error;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Get the JSON form of a response type
+///
+/// # Errors
+///
+/// Returns [`Error::Json`] if the value cannot be serialized.
+pub fn to_value<T: Serialize>(value: &T) -> Result<Value, Error> {
+    Ok(serde_json::to_value(value)?)
+}
+
+/// Get the JSON form of one top-level field of a response type
+///
+/// `name` is the field's name in the API response, e.g. `"segments"`.
+/// Returns `None` if the field is absent.
+///
+/// # Errors
+///
+/// Returns [`Error::Json`] if the value cannot be serialized.
+pub fn field<T: Serialize>(value: &T, name: &str) -> Result<Option<Value>, Error> {
+    match to_value(value)? {
+        Value::Object(mut fields) => Ok(fields.remove(name)),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PlayerStats, parse_response};
+
+    #[test]
+    fn test_to_value_keeps_unmodelled_fields() {
+        let json = r#"{
+            "player_id": "p-1",
+            "game_id": "cs2",
+            "lifetime": {"Matches": "120", "Win Rate %": "54"},
+            "segments": [{"label": "de_mirage", "mode": "5v5", "stats": {"Kills": "900"}, "rank": 3}],
+            "region": "EU"
+        }"#;
+        let stats: PlayerStats = parse_response(json).unwrap();
+
+        assert_eq!(
+            to_value(&stats).unwrap(),
+            serde_json::from_str::<Value>(json).unwrap()
+        );
+        assert_eq!(field(&stats, "segments").unwrap().unwrap()[0]["rank"], 3);
+        assert_eq!(field(&stats, "missing").unwrap(), None);
+    }
+}
@@ -0,0 +1,82 @@
+//! Links to the FACEIT website
+//!
+//! Builds the canonical `faceit.com` URLs for matches, players and hubs from
+//! their IDs, for payloads that omit `faceit_url`. URLs use the English site;
+//! pass them through [`localize`] to switch language.
+//!
+//! # Examples
+//!
+//! ```
+//! use faceit::urls;
+//!
+//! assert_eq!(
+//!     urls::match_url("1-8f3c2e8a", "cs2"),
+//!     "https://www.faceit.com/en/cs2/room/1-8f3c2e8a"
+//! );
+//! assert_eq!(urls::player_url("s1mple"), "https://www.faceit.com/en/players/s1mple");
+//! ```
+
+/// Root of the FACEIT website
+const SITE_URL: &str = "https://www.faceit.com";
+
+/// Language used by the URL builders
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// Placeholder the API puts in `faceit_url` fields instead of a language
+const LANGUAGE_PLACEHOLDER: &str = "{lang}";
+
+/// URL of a match room
+///
+/// # Arguments
+/// * `match_id` - The FACEIT match ID
+/// * `game` - The game ID the match was played in (e.g. "cs2")
+pub fn match_url(match_id: &str, game: &str) -> String {
+    format!("{SITE_URL}/{DEFAULT_LANGUAGE}/{game}/room/{match_id}")
+}
+
+/// URL of a player profile
+///
+/// # Arguments
+/// * `nickname` - The player's FACEIT nickname
+pub fn player_url(nickname: &str) -> String {
+    format!("{SITE_URL}/{DEFAULT_LANGUAGE}/players/{nickname}")
+}
+
+/// URL of a hub
+///
+/// # Arguments
+/// * `hub_id` - The hub ID
+pub fn hub_url(hub_id: &str) -> String {
+    format!("{SITE_URL}/{DEFAULT_LANGUAGE}/hub/{hub_id}")
+}
+
+/// Set the language of a FACEIT website URL
+///
+/// Fills in the `{lang}` placeholder of `faceit_url` fields returned by the
+/// API, or replaces the language of a URL built by this module. Other URLs
+/// are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use faceit::urls;
+///
+/// assert_eq!(
+///     urls::localize("https://www.faceit.com/{lang}/players/s1mple", "de"),
+///     "https://www.faceit.com/de/players/s1mple"
+/// );
+/// assert_eq!(
+///     urls::localize(&urls::hub_url("hub-id"), "fr"),
+///     "https://www.faceit.com/fr/hub/hub-id"
+/// );
+/// ```
+pub fn localize(url: &str, language: &str) -> String {
+    if url.contains(LANGUAGE_PLACEHOLDER) {
+        return url.replace(LANGUAGE_PLACEHOLDER, language);
+    }
+    let default_prefix = format!("{SITE_URL}/{DEFAULT_LANGUAGE}/");
+    match url.strip_prefix(&default_prefix) {
+        Some(path) => format!("{SITE_URL}/{language}/{path}"),
+        None => url.to_string(),
+    }
+}
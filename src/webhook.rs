@@ -0,0 +1,202 @@
+//! Webhook event types and signature verification
+//!
+//! FACEIT can push match, hub and championship events to a webhook endpoint
+//! instead of requiring you to poll the Data API. This module provides the
+//! callback envelope type and a helper to verify that a callback was signed
+//! with your webhook secret.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use faceit::webhook::{verify_signature, Event, EventKind};
+//! use http::HeaderMap;
+//!
+//! # fn handle(headers: &HeaderMap, body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+//! verify_signature("your-webhook-secret", headers, body)?;
+//!
+//! let event = Event::from_slice(body)?;
+//! if event.event == EventKind::MatchStatusFinished {
+//!     println!("Match finished: {:?}", event.match_id());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Error;
+use ::http::HeaderMap;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+
+/// Header carrying the hex-encoded HMAC-SHA256 signature of the request body
+pub const SIGNATURE_HEADER: &str = "X-Faceit-Signature";
+
+/// Errors returned when verifying a webhook callback
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum WebhookError {
+    #[error("Missing signature header: {0}")]
+    MissingSignature(&'static str),
+
+    #[error("Malformed signature header")]
+    MalformedSignature,
+
+    #[error("Signature does not match request body")]
+    InvalidSignature,
+}
+
+/// Verify the signature of a webhook callback
+///
+/// Computes the HMAC-SHA256 of `body` using `secret` and compares it, in
+/// constant time, against the hex-encoded value of the [`SIGNATURE_HEADER`]
+/// header. The body must be the raw bytes as received, before any parsing.
+///
+/// `headers` is the [`http`](::http) crate's header map, as exposed by axum,
+/// hyper, actix-web 4 and other frameworks built on it.
+///
+/// # Errors
+///
+/// Returns [`WebhookError::MissingSignature`] if the header is absent,
+/// [`WebhookError::MalformedSignature`] if it is not valid hex, and
+/// [`WebhookError::InvalidSignature`] if the signature does not match.
+pub fn verify_signature(
+    secret: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<(), WebhookError> {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .ok_or(WebhookError::MissingSignature(SIGNATURE_HEADER))?
+        .to_str()
+        .map_err(|_| WebhookError::MalformedSignature)?;
+    let signature = hex::decode(signature.trim()).map_err(|_| WebhookError::MalformedSignature)?;
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Kind of webhook event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    MatchObjectCreated,
+    MatchStatusConfiguring,
+    MatchStatusReady,
+    MatchStatusFinished,
+    MatchStatusCancelled,
+    MatchStatusAborted,
+    MatchDemoReady,
+    HubUserAdded,
+    HubUserRemoved,
+    HubUserRoleAdded,
+    HubUserRoleRemoved,
+    ChampionshipCreated,
+    ChampionshipStarted,
+    ChampionshipFinished,
+    ChampionshipCancelled,
+    /// An event kind not known to this version of the crate
+    #[serde(other)]
+    Unknown,
+}
+
+/// Webhook callback envelope
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    #[serde(rename = "transaction_id")]
+    pub transaction_id: String,
+    pub event: EventKind,
+    #[serde(rename = "event_id")]
+    pub event_id: String,
+    #[serde(rename = "third_party_id", skip_serializing_if = "Option::is_none")]
+    pub third_party_id: Option<String>,
+    #[serde(rename = "app_id", skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(rename = "retry_count", skip_serializing_if = "Option::is_none")]
+    pub retry_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+    pub payload: serde_json::Value,
+}
+
+impl Event {
+    /// Parse a webhook callback body
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the body is not a valid callback envelope.
+    pub fn from_slice(body: &[u8]) -> Result<Self, Error> {
+        serde_json::from_slice(body).map_err(Error::Json)
+    }
+
+    /// Get the match ID for match events
+    ///
+    /// Match event payloads carry the match ID in their `id` field.
+    pub fn match_id(&self) -> Option<&str> {
+        match self.event {
+            EventKind::MatchObjectCreated
+            | EventKind::MatchStatusConfiguring
+            | EventKind::MatchStatusReady
+            | EventKind::MatchStatusFinished
+            | EventKind::MatchStatusCancelled
+            | EventKind::MatchStatusAborted
+            | EventKind::MatchDemoReady => self.payload.get("id")?.as_str(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &[u8] =
+        br#"{"transaction_id":"t-1","event":"match_status_finished","event_id":"e-1","payload":{"id":"1-abc"}}"#;
+
+    fn signed_headers(signature: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(BODY);
+        let signature = hex::encode(mac.finalize().into_bytes());
+
+        assert_eq!(
+            verify_signature("secret", &signed_headers(&signature), BODY),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature("other-secret", &signed_headers(&signature), BODY),
+            Err(WebhookError::InvalidSignature)
+        );
+        assert_eq!(
+            verify_signature("secret", &signed_headers("not-hex"), BODY),
+            Err(WebhookError::MalformedSignature)
+        );
+        assert_eq!(
+            verify_signature("secret", &HeaderMap::new(), BODY),
+            Err(WebhookError::MissingSignature(SIGNATURE_HEADER))
+        );
+    }
+
+    #[test]
+    fn test_event_parsing() {
+        let event = Event::from_slice(BODY).unwrap();
+        assert_eq!(event.event, EventKind::MatchStatusFinished);
+        assert_eq!(event.match_id(), Some("1-abc"));
+
+        let event = Event::from_slice(
+            br#"{"transaction_id":"t","event":"some_new_event","event_id":"e","payload":{}}"#,
+        )
+        .unwrap();
+        assert_eq!(event.event, EventKind::Unknown);
+    }
+}